@@ -0,0 +1,124 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use color_eyre::eyre::{bail, eyre, WrapErr};
+use color_eyre::{Report, Result};
+use serde::Deserialize;
+
+/// The known answers of a puzzle input, as stored in a day's `answers.toml`:
+///
+/// ```toml
+/// part1 = 55002
+/// part2 = "55093"
+/// ```
+///
+/// Answers can be integers or strings and are compared with the displayed answer of a part.
+/// A part without an answer is not checked.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawAnswers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+/// The outcome of comparing an answer with the stored one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Check {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    /// The stored answer of `part`.
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compares `answer` with the stored answer of `part`.
+    #[must_use]
+    pub fn check(&self, part: u8, answer: &impl Display) -> Check {
+        match self.get(part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer.to_string() => Check::Correct,
+            Some(expected) => Check::Wrong { expected: expected.to_owned() },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw: RawAnswers = toml::from_str(s).wrap_err("Cannot parse answers")?;
+        Ok(Self { part1: raw.part1.map(answer).transpose()?, part2: raw.part2.map(answer).transpose()? })
+    }
+}
+
+fn answer(value: toml::Value) -> Result<String> {
+    match value {
+        toml::Value::String(s) => Ok(s),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        value => Err(eyre!("An answer has to be an integer or a string, not {value}")),
+    }
+}
+
+/// Loads the answers to check against, mirroring [`crate::load_input`].
+///
+/// The answers are read from `path`. Without a path, the `embedded` answers belong to the
+/// embedded input, so they are only used if no `input` path was given either.
+///
+/// # Errors
+/// If the answers cannot be read or parsed, or if there are no answers for the input.
+pub fn load_answers(path: Option<&Path>, input: Option<&Path>, embedded: Option<&'static str>) -> Result<Answers> {
+    match (path, input, embedded) {
+        (Some(path), _, _) => fs::read_to_string(path)
+            .wrap_err_with(|| format!("Cannot read answers {}", path.display()))?
+            .parse(),
+        (None, Some(_), _) => bail!("The stored answers belong to the embedded input, pass `--answers <path>` to check another input"),
+        (None, None, Some(embedded)) => embedded.parse(),
+        (None, None, None) => bail!("No answers to check against: pass `--answers <path>`, or build with the `embedded-input` feature"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_answers() {
+        let answers: Answers = "part1 = 55002\npart2 = \"abc\"\n".parse().unwrap();
+        assert_eq!(answers, Answers { part1: Some("55002".to_owned()), part2: Some("abc".to_owned()) });
+        assert_eq!("# unsolved\n".parse::<Answers>().unwrap(), Answers::default());
+        assert!("part3 = 1".parse::<Answers>().is_err());
+        assert!("part1 = 1.5".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn it_checks_answers() {
+        let answers = Answers { part1: Some("42".to_owned()), part2: None };
+        assert_eq!(answers.check(1, &42), Check::Correct);
+        assert_eq!(answers.check(1, &"41"), Check::Wrong { expected: "42".to_owned() });
+        assert_eq!(answers.check(2, &42), Check::Unknown);
+    }
+
+    #[test]
+    fn it_only_uses_embedded_answers_for_embedded_input() {
+        assert_eq!(load_answers(None, None, Some("part1 = 1")).unwrap().part1.as_deref(), Some("1"));
+        assert!(load_answers(None, Some(Path::new("input.txt")), Some("part1 = 1")).is_err());
+        assert!(load_answers(None, None, None).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use criterion::{black_box, Criterion};
+
+use crate::Solution;
+
+/// Benchmarks parsing `input` and solving each of `parts` of it, as `dayNN/parse/<name>`,
+/// `dayNN/part1/<name>` and `dayNN/part2/<name>`.
+///
+/// The parts are benchmarked on the input parsed once beforehand.
+///
+/// # Panics
+/// If the input cannot be parsed, or if a part is neither 1 nor 2.
+pub fn bench<S: Solution>(c: &mut Criterion, name: &str, input: &str, parts: &[u8]) {
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.bench_function(format!("parse/{name}"), |b| b.iter(|| S::parse(black_box(input))));
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("Cannot parse {name}: {e}"));
+    for &part in parts {
+        match part {
+            1 => group.bench_function(format!("part1/{name}"), |b| b.iter(|| S::part1(black_box(&parsed)))),
+            2 => group.bench_function(format!("part2/{name}"), |b| b.iter(|| S::part2(black_box(&parsed)))),
+            _ => panic!("There is no part {part}"),
+        };
+    }
+    group.finish();
+}
+
+/// Benchmarks the real puzzle input like [`bench`], named `input`, if the day was built with it.
+///
+/// # Panics
+/// If the input cannot be parsed, or if a part is neither 1 nor 2.
+pub fn bench_input<S: Solution>(c: &mut Criterion, input: Option<&str>, parts: &[u8]) {
+    if let Some(input) = input {
+        bench::<S>(c, "input", input, parts);
+    }
+}
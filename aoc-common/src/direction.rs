@@ -0,0 +1,136 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// One of the four orthogonal headings on a grid, where north is up and the y axis grows south.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// The `(dx, dy)` of one step in this direction.
+    #[must_use]
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Self::North => (0, -1),
+            Self::East => (1, 0),
+            Self::South => (0, 1),
+            Self::West => (-1, 0),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+
+    #[must_use]
+    pub const fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    /// Parses `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or an arrow `^`/`>`/`v`/`<`.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | 'N' | '^' => Ok(Self::North),
+            'R' | 'E' | '>' => Ok(Self::East),
+            'D' | 'S' | 'v' => Ok(Self::South),
+            'L' | 'W' | '<' => Ok(Self::West),
+            _ => Err(ParseError::new("Not a direction", value)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(ParseError::new("Not a direction", s)),
+        }
+    }
+}
+
+impl Display for Direction {
+    /// Displays the direction as an arrow.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::North => '^',
+            Self::East => '>',
+            Self::South => 'v',
+            Self::West => '<',
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.opposite().opposite(), direction);
+            let (dx, dy) = direction.delta();
+            assert_eq!(direction.opposite().delta(), (-dx, -dy));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn it_parses_and_displays() {
+        for (s, direction) in [("U", Direction::North), ("E", Direction::East), ("v", Direction::South), ("L", Direction::West)] {
+            assert_eq!(s.parse::<Direction>().unwrap(), direction);
+            assert_eq!(direction.to_string().parse::<Direction>().unwrap(), direction);
+        }
+        assert!("X".parse::<Direction>().is_err());
+        assert!("UD".parse::<Direction>().is_err());
+    }
+}
@@ -0,0 +1,403 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::direction::Direction;
+use crate::ParseError;
+
+/// A position in a [`Grid`] as `(x, y)`, with `(0, 0)` being the top left cell.
+pub type Coordinate = (usize, usize);
+
+const NEIGHBOURS8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangular grid of cells, stored row by row in a flat `Vec`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// # Errors
+    /// If the number of cells does not match `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() == width * height {
+            Ok(Self { cells, width, height })
+        } else {
+            Err(eyre!("Expected {width}x{height} cells, got {}", cells.len()))
+        }
+    }
+
+    /// Creates a grid by calling `f` for every coordinate.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Coordinate) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(&mut f).collect();
+        Self { cells, width, height }
+    }
+
+    /// Parses a grid of characters, mapping each one with `f`.
+    ///
+    /// Trailing whitespace and `\r\n` line endings are ignored. Errors of `f` are moved to the
+    /// character that caused them.
+    ///
+    /// # Errors
+    /// If the input is empty, the rows have different lengths or `f` fails.
+    pub fn parse_with<E: Into<ParseError>>(s: &str, mut f: impl FnMut(char) -> Result<T, E>) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in s.trim_end().lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = &line[i..i + c.len_utf8()];
+                cells.push(f(c).map_err(|e| {
+                    let mut error: ParseError = e.into();
+                    if error.text.is_empty() {
+                        cell.clone_into(&mut error.text);
+                    }
+                    error.within(s, cell)
+                })?);
+            }
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at(s, line, format!("Row has {row_width} cells, expected {width}")));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self { cells, width, height }),
+            _ => Err(ParseError::new("Cannot parse an empty grid", s)),
+        }
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    #[must_use]
+    pub const fn contains(&self, (x, y): Coordinate) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The index of `coordinate` in the row-major cells, if it is inside the grid.
+    #[must_use]
+    pub const fn index_of(&self, coordinate: Coordinate) -> Option<usize> {
+        if self.contains(coordinate) {
+            Some(coordinate.1 * self.width + coordinate.0)
+        } else {
+            None
+        }
+    }
+
+    /// The coordinate of the cell at `index` in the row-major cells.
+    #[must_use]
+    pub const fn coordinate_of(&self, index: usize) -> Coordinate {
+        (index % self.width, index / self.width)
+    }
+
+    #[must_use]
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.index_of(coordinate).map(|i| &self.cells[i])
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        self.index_of(coordinate).map(|i| &mut self.cells[i])
+    }
+
+    /// Gets the cell at `(x, y)` as if the grid was repeated infinitely in every direction.
+    #[must_use]
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        &self[self.wrap(x, y)]
+    }
+
+    /// Maps `(x, y)` of the infinitely repeated grid back onto the grid.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub const fn wrap(&self, x: isize, y: isize) -> Coordinate {
+        (x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)
+    }
+
+    /// Moves `coordinate` by `(dx, dy)`, if the result is still inside the grid.
+    #[must_use]
+    pub fn offset(&self, (x, y): Coordinate, dx: isize, dy: isize) -> Option<Coordinate> {
+        let coordinate = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(coordinate).then_some(coordinate)
+    }
+
+    /// Moves `coordinate` one step towards `direction`, if the result is still inside the grid.
+    #[must_use]
+    pub fn step(&self, coordinate: Coordinate, direction: Direction) -> Option<Coordinate> {
+        let (dx, dy) = direction.delta();
+        self.offset(coordinate, dx, dy)
+    }
+
+    /// The orthogonal neighbours of `coordinate` inside the grid, clockwise starting north.
+    pub fn neighbours4(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(coordinate, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `coordinate` inside the grid, clockwise starting north.
+    pub fn neighbours8(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |&(dx, dy)| self.offset(coordinate, dx, dy))
+    }
+
+    /// The cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells together with their coordinates in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.coordinate_of(i), cell))
+    }
+
+    /// The coordinate of the first cell in row-major order matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coordinate> {
+        self.cells.iter().position(predicate).map(|i| self.coordinate_of(i))
+    }
+
+    /// # Panics
+    /// If `y` is outside the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {y} is outside of the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width)
+    }
+
+    /// # Panics
+    /// If `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// # Errors
+    /// The first error returned by `f`.
+    pub fn try_map<U, E>(&self, f: impl FnMut(&T) -> Result<U, E>) -> Result<Grid<U>, E> {
+        Ok(Grid { cells: self.cells.iter().map(f).collect::<Result<_, _>>()?, width: self.width, height: self.height })
+    }
+
+    /// Displays the grid row by row, mapping each cell to a character with `f`.
+    pub fn display_with<'a>(&'a self, f: impl Fn(&T) -> char + 'a) -> impl Display + 'a {
+        DisplayWith { grid: self, f }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self { cells: vec![value; width * height], width, height }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, self.height - 1 - x)].clone())
+    }
+
+    #[must_use]
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(self.width - 1 - y, x)].clone())
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.get(coordinate).unwrap_or_else(|| panic!("{coordinate:?} is outside of the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coordinate).unwrap_or_else(|| panic!("{coordinate:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Grid<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<ParseError>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, T::try_from)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                write!(f, "{}", (self.f)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\r\ndef\r\n";
+
+    #[test]
+    fn it_parses_and_displays() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.display_with(char::to_ascii_uppercase).to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn it_rejects_ragged_and_empty_input() {
+        assert!("abc\nde\n".parse::<Grid<char>>().is_err());
+        assert!("\n".parse::<Grid<char>>().is_err());
+        let err = Grid::parse_with("ab\na#", |c| if c == '#' { Err("Illegal character") } else { Ok(c) }).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "#"));
+    }
+
+    #[test]
+    fn it_checks_bounds() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.offset((0, 0), -1, 0), None);
+        assert_eq!(grid.offset((2, 0), 1, 0), None);
+        assert_eq!(grid.offset((0, 1), 2, -1), Some((2, 0)));
+        assert_eq!(grid.step((0, 1), Direction::North), Some((0, 0)));
+        assert_eq!(grid.step((0, 1), Direction::South), None);
+        assert_eq!(grid.index_of((3, 0)), None);
+        assert_eq!(grid.index_of((0, 1)), Some(3));
+        assert_eq!(grid.coordinate_of(5), (2, 1));
+    }
+
+    #[test]
+    fn it_wraps() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(*grid.get_wrapping(-1, -1), 'f');
+        assert_eq!(*grid.get_wrapping(3, 2), 'a');
+        assert_eq!(*grid.get_wrapping(7, 5), 'e');
+    }
+
+    #[test]
+    fn it_gets_neighbours() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4((1, 1)).collect::<Vec<_>>(), [(1, 0), (2, 1), (0, 1)]);
+        assert_eq!(grid.neighbours8((2, 0)).collect::<Vec<_>>(), [(2, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn it_iterates_rows_and_columns() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(Iterator::collect::<String>).collect::<Vec<_>>(), ["ad", "be", "cf"]);
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn it_rotates_and_transposes() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+}
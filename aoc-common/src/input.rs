@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+
+/// Loads the puzzle input at runtime.
+///
+/// The input is read from `path`, where `-` stands for stdin. Without a path, the `embedded`
+/// input is used if the day was built with it, and otherwise the input is read from stdin
+/// unless stdin is a terminal.
+///
+/// # Errors
+/// If the input cannot be read, or if there is neither a path, an embedded input nor piped stdin.
+pub fn load_input(path: Option<&Path>, embedded: Option<&'static str>) -> Result<Cow<'static, str>> {
+    match (path, embedded) {
+        (Some(path), _) if path == Path::new("-") => read_stdin().map(Cow::Owned),
+        (Some(path), _) => fs::read_to_string(path)
+            .map(Cow::Owned)
+            .wrap_err_with(|| format!("Cannot read input {}", path.display())),
+        (None, Some(embedded)) => Ok(Cow::Borrowed(embedded)),
+        (None, None) if !io::stdin().is_terminal() => read_stdin().map(Cow::Owned),
+        (None, None) => Err(eyre!("No input given: pass `--input <path>`, pipe it to stdin, or build with the `embedded-input` feature")),
+    }
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).wrap_err("Cannot read input from stdin")?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_prefers_path_over_embedded_input() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let input = load_input(Some(&path), Some("embedded")).unwrap();
+        assert!(input.contains("aoc-common"));
+    }
+
+    #[test]
+    fn it_falls_back_to_embedded_input() {
+        assert_eq!(load_input(None, Some("embedded")).unwrap(), "embedded");
+    }
+
+    #[test]
+    fn it_reports_missing_file() {
+        let err = load_input(Some(Path::new("does/not/exist.txt")), None).unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
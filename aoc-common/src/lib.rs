@@ -0,0 +1,55 @@
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod direction;
+pub mod grid;
+pub mod parse;
+pub mod pathfinding;
+pub mod report;
+mod input;
+mod macros;
+mod runner;
+
+use std::fmt::Display;
+
+use color_eyre::Result;
+
+pub use answers::{load_answers, Answers};
+pub use input::load_input;
+pub use parse::ParseError;
+#[cfg(feature = "report")]
+pub use report::Alloc;
+pub use runner::{main, run, solve, RunArgs};
+
+/// The solution of one day's puzzle.
+///
+/// The input is parsed once and then shared by both parts, so parts that need to mutate it
+/// work on a clone.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+    /// Whether [`Solution::part2`] is implemented. If not, only part 1 is solved by default.
+    const PART2_SOLVED: bool = true;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parses the raw puzzle input.
+    ///
+    /// # Errors
+    /// If the input is malformed.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Solves part 1 of the puzzle.
+    ///
+    /// # Errors
+    /// If the puzzle has no answer for this input.
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    /// Solves part 2 of the puzzle.
+    ///
+    /// # Errors
+    /// If the puzzle has no answer for this input, or if part 2 is not solved yet.
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+}
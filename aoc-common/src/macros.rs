@@ -0,0 +1,58 @@
+/// Defines the day's `INPUT` and `ANSWERS` constants, which hold `src/input.txt` and
+/// `src/answers.toml` if the crate was built with its `embedded-input` feature.
+///
+/// ```ignore
+/// aoc_common::embedded_input!();
+/// ```
+#[macro_export]
+macro_rules! embedded_input {
+    () => {
+        /// The puzzle input, if the crate was built with the `embedded-input` feature.
+        #[cfg(feature = "embedded-input")]
+        pub const INPUT: Option<&str> = Some(include_str!("input.txt"));
+        /// The puzzle input, if the crate was built with the `embedded-input` feature.
+        #[cfg(not(feature = "embedded-input"))]
+        pub const INPUT: Option<&str> = None;
+        /// The answers to [`INPUT`], if the crate was built with the `embedded-input` feature.
+        #[cfg(feature = "embedded-input")]
+        pub const ANSWERS: Option<&str> = Some(include_str!("answers.toml"));
+        /// The answers to [`INPUT`], if the crate was built with the `embedded-input` feature.
+        #[cfg(not(feature = "embedded-input"))]
+        pub const ANSWERS: Option<&str> = None;
+    };
+}
+
+/// Installs [`Alloc`](crate::Alloc) as the global allocator with the `report` feature, so
+/// `--report` can measure the allocations of every step.
+#[cfg(feature = "report")]
+#[macro_export]
+macro_rules! global_allocator {
+    () => {
+        #[global_allocator]
+        static ALLOC: $crate::Alloc = $crate::Alloc;
+    };
+}
+
+/// Keeps the system allocator without the `report` feature, which tracking allocations would
+/// slow down.
+#[cfg(not(feature = "report"))]
+#[macro_export]
+macro_rules! global_allocator {
+    () => {};
+}
+
+/// The binary of a day, solving the puzzle of `$day::$solution` with the day's embedded input.
+///
+/// ```ignore
+/// aoc_common::main!(day01::Day01);
+/// ```
+#[macro_export]
+macro_rules! main {
+    ($day:ident::$solution:ident) => {
+        $crate::global_allocator!();
+
+        fn main() -> ::color_eyre::Result<()> {
+            $crate::main::<$day::$solution>($day::INPUT, $day::ANSWERS)
+        }
+    };
+}
@@ -0,0 +1,157 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use nom::combinator::all_consuming;
+use nom::{Finish, Parser};
+
+/// An error while parsing puzzle input, pointing at the offending text.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The line of the offending text, starting at 1.
+    pub line: usize,
+    /// The column of the offending text in characters, starting at 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    /// What is wrong with the text.
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error about `text`, located at the start of the parsed input until it is moved
+    /// with [`ParseError::within`].
+    #[must_use]
+    pub fn new(message: impl Display, text: impl Into<String>) -> Self {
+        Self { line: 1, column: 1, text: text.into(), message: message.to_string() }
+    }
+
+    /// Creates an error about `text`, a slice of `input`, located at its position within `input`.
+    #[must_use]
+    pub fn at(input: &str, text: &str, message: impl Display) -> Self {
+        Self::new(message, text).within(input, text)
+    }
+
+    /// Moves an error from parsing `part`, a slice of `input`, to its position within `input`.
+    ///
+    /// If `part` is not a slice of `input`, the error is not moved.
+    #[must_use]
+    pub fn within(mut self, input: &str, part: &str) -> Self {
+        if let Some((line, column)) = position(input, part) {
+            if self.line == 1 {
+                self.column += column - 1;
+            }
+            self.line += line - 1;
+        }
+        self
+    }
+
+    /// Converts the error of a nom parser that was given `input`.
+    #[must_use]
+    pub fn from_nom(input: &str, error: &nom::error::Error<&str>) -> Self {
+        let text = error.input.lines().next().unwrap_or_default();
+        Self::at(input, text, format!("Unexpected input, expected {}", error.code.description()))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A bare message, without any offending text yet.
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        Self::new(message, "")
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+/// Parses every line of `input`, moving errors to the line they occurred on.
+///
+/// # Errors
+/// The error of the first line that cannot be parsed.
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input.lines().map(|line| line.parse().map_err(|e: ParseError| e.within(input, line))).collect()
+}
+
+/// Runs a nom `parser` that has to consume all of `input`.
+///
+/// # Errors
+/// If the parser fails or does not consume all input.
+pub fn complete<'a, O>(input: &'a str, parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>) -> Result<O, ParseError> {
+    all_consuming(parser)(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|e| ParseError::from_nom(input, &e))
+}
+
+/// The line and column of `part` within `input`, if it is a slice of it.
+fn position(input: &str, part: &str) -> Option<(usize, usize)> {
+    let offset = (part.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+    if offset + part.len() > input.len() {
+        return None;
+    }
+    let before = input.get(..offset)?;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Some((before.matches('\n').count() + 1, before[line_start..].chars().count() + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::{char, digit1};
+    use nom::sequence::separated_pair;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let digits = s.trim_start();
+            digits.parse().map(Self).map_err(|e| ParseError::at(s, digits, e))
+        }
+    }
+
+    #[test]
+    fn it_locates_errors() {
+        let input = "12\n  3x\n4";
+        let err = parse_lines::<Number>(input).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "3x"));
+        assert_eq!(err.to_string(), "line 2, column 3: invalid digit found in string: `3x`");
+        assert_eq!(parse_lines::<Number>("1\n 2").unwrap().iter().map(|n| n.0).sum::<u32>(), 3);
+    }
+
+    #[test]
+    fn it_does_not_move_errors_of_unrelated_text() {
+        let err = ParseError::new("Oops", "x").within("abc", "other");
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn it_converts_nom_errors() {
+        let input = "1,2\n3;4";
+        let parser = separated_pair(digit1, char(','), digit1);
+        assert_eq!(complete("1,2", separated_pair(digit1, char(','), digit1)).unwrap(), ("1", "2"));
+        let err = complete(input, parser).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 4, ""));
+        let err = complete("3;4", separated_pair(digit1, char(','), digit1)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, ";4"));
+    }
+}
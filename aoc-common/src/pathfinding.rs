@@ -0,0 +1,196 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A graph the search algorithms of this module can walk.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes reachable from `node` in one step, with the cost of that step.
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, usize)>;
+}
+
+/// A path found by a search, from the start to the goal node, both included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+/// Finds the cheapest path from `start` to a node matching `is_goal`.
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node, is_goal: impl FnMut(&G::Node) -> bool) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Finds the cheapest path from `start` to a node matching `is_goal`, exploring the nodes with the
+/// lowest cost plus `heuristic` first.
+///
+/// The heuristic must never overestimate the remaining cost, or the path might not be the cheapest.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> usize,
+) -> Option<Path<G::Node>> {
+    let mut costs: HashMap<G::Node, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut queue = BinaryHeap::from([State { estimate: heuristic(&start), cost: 0, node: start }]);
+    while let Some(State { cost, node, .. }) = queue.pop() {
+        if is_goal(&node) {
+            return Some(Path { nodes: reconstruct(&previous, node), cost });
+        }
+        if cost > costs[&node] {
+            // a cheaper way to this node was already explored
+            continue;
+        }
+        for (neighbour, step) in graph.neighbours(&node) {
+            let neighbour_cost = cost + step;
+            if costs.get(&neighbour).is_none_or(|&known| neighbour_cost < known) {
+                costs.insert(neighbour.clone(), neighbour_cost);
+                previous.insert(neighbour.clone(), node.clone());
+                queue.push(State { estimate: neighbour_cost + heuristic(&neighbour), cost: neighbour_cost, node: neighbour });
+            }
+        }
+    }
+    None
+}
+
+/// Finds the path with the fewest steps from `start` to a node matching `is_goal`, ignoring the
+/// step costs. The cost of the path is its number of steps.
+pub fn bfs<G: Graph>(graph: &G, start: G::Node, mut is_goal: impl FnMut(&G::Node) -> bool) -> Option<Path<G::Node>> {
+    let mut previous: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut queue = VecDeque::from([start.clone()]);
+    let mut seen = HashSet::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = reconstruct(&previous, node);
+            return Some(Path { cost: nodes.len() - 1, nodes });
+        }
+        for (neighbour, _) in graph.neighbours(&node) {
+            if seen.insert(neighbour.clone()) {
+                previous.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    None
+}
+
+/// The number of steps to every node reachable from `start` in at most `max_steps` steps.
+pub fn bfs_distances<G: Graph>(graph: &G, start: G::Node, max_steps: usize) -> HashMap<G::Node, usize> {
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, distance)) = queue.pop_front() {
+        if distance == max_steps {
+            continue;
+        }
+        for (neighbour, _) in graph.neighbours(&node) {
+            if !distances.contains_key(&neighbour) {
+                distances.insert(neighbour.clone(), distance + 1);
+                queue.push_back((neighbour, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(previous: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(node) = previous.get(&nodes[nodes.len() - 1]) {
+        nodes.push(node.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+/// An entry of the priority queue, ordered so the [`BinaryHeap`] pops the lowest estimate first.
+struct State<N> {
+    estimate: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for State<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for State<N> {}
+
+impl<N> PartialOrd for State<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for State<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // prefer the node further along when the estimates are equal
+        other.estimate.cmp(&self.estimate).then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Coordinate, Grid};
+
+    /// Walls are `0`, every other cell costs its value to enter.
+    struct Maze(Grid<usize>);
+
+    impl Graph for Maze {
+        type Node = Coordinate;
+
+        fn neighbours(&self, node: &Coordinate) -> impl Iterator<Item = (Coordinate, usize)> {
+            self.0.neighbours4(*node).map(|n| (n, self.0[n])).filter(|(_, cost)| *cost > 0)
+        }
+    }
+
+    fn maze() -> Maze {
+        let grid = Grid::parse_with("1191\n1091\n1111\n", |c| c.to_digit(10).map(|d| d as usize).ok_or("Not a digit")).unwrap();
+        Maze(grid)
+    }
+
+    #[test]
+    fn it_finds_cheapest_path() {
+        let maze = maze();
+        let path = dijkstra(&maze, (0, 0), |n| *n == (3, 0)).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2), (3, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn it_finds_cheapest_path_with_heuristic() {
+        let maze = maze();
+        let manhattan = |(x, y): &Coordinate| x.abs_diff(3) + y.abs_diff(0);
+        let path = astar(&maze, (0, 0), |n| *n == (3, 0), manhattan).unwrap();
+        assert_eq!(path, dijkstra(&maze, (0, 0), |n| *n == (3, 0)).unwrap());
+    }
+
+    #[test]
+    fn it_finds_shortest_path() {
+        let maze = maze();
+        let path = bfs(&maze, (0, 0), |n| *n == (3, 0)).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [(0, 0), (1, 0), (2, 0), (3, 0)]);
+    }
+
+    #[test]
+    fn it_does_not_find_unreachable_goal() {
+        let maze = maze();
+        assert_eq!(dijkstra(&maze, (0, 0), |n| *n == (1, 1)), None);
+        assert_eq!(bfs(&maze, (0, 0), |n| *n == (1, 1)), None);
+        assert_eq!(dijkstra(&maze, (0, 0), |n| *n == (0, 0)).unwrap().nodes, [(0, 0)]);
+    }
+
+    #[test]
+    fn it_gets_distances() {
+        let maze = maze();
+        let distances = bfs_distances(&maze, (0, 0), 2);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&(2, 0)], 2);
+        assert!(!distances.contains_key(&(3, 0)));
+        assert_eq!(bfs_distances(&maze, (0, 0), usize::MAX).len(), 11);
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+/// The global allocator every binary installs with the `report` feature, so `--report` can
+/// measure the allocations:
+///
+/// ```ignore
+/// aoc_common::global_allocator!();
+/// ```
+///
+/// Without it, every allocation is reported as 0.
+#[cfg(feature = "report")]
+pub use dhat::Alloc;
+
+/// How `--report` prints the measurements of a run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    /// A line per step after the answers
+    Human,
+    /// A JSON object with the answers and measurements instead of the answers
+    Json,
+}
+
+/// The measurements of one run of a day.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub steps: Vec<Step>,
+}
+
+/// The wall time and heap allocation of one step of a run: parsing or solving a part.
+#[derive(Clone, Debug, Serialize)]
+pub struct Step {
+    /// `parse`, `part1` or `part2`.
+    pub name: &'static str,
+    /// The answer of a part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// The stored answer of a part, when checking against it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(rename = "time_ns", serialize_with = "nanos")]
+    pub time: Duration,
+    /// The most bytes allocated by the step at once, if allocations were tracked.
+    pub peak_bytes: Option<usize>,
+    /// All bytes allocated by the step, freed or not, if allocations were tracked.
+    pub total_bytes: Option<usize>,
+}
+
+/// Runs `f` as the step `name`, measuring its wall time and, with `track_allocations` and the
+/// `report` feature, its peak and total heap allocation.
+///
+/// Tracking allocations slows down allocation heavy steps many times over, so they are tracked in
+/// a second run of `f` after the timed one, whose output is discarded. Only one step can be
+/// tracked at a time.
+///
+/// # Panics
+/// If allocations are tracked while another step is tracking them.
+pub fn measure<T>(name: &'static str, track_allocations: bool, f: impl Fn() -> T) -> (T, Step) {
+    let start = Instant::now();
+    let output = f();
+    let time = start.elapsed();
+    let (peak_bytes, total_bytes) = if track_allocations { allocations(f) } else { (None, None) };
+    (output, Step { name, answer: None, expected: None, time, peak_bytes, total_bytes })
+}
+
+/// The peak and total bytes allocated by running `f`.
+#[cfg(feature = "report")]
+fn allocations<T>(f: impl Fn() -> T) -> (Option<usize>, Option<usize>) {
+    let _profiler = dhat::Profiler::builder().testing().trim_backtraces(Some(1)).build();
+    let _tracked = f();
+    let stats = dhat::HeapStats::get();
+    (Some(stats.max_bytes), Some(usize::try_from(stats.total_bytes).unwrap_or(usize::MAX)))
+}
+
+/// Nothing, as allocations are only tracked with the `report` feature.
+#[cfg(not(feature = "report"))]
+fn allocations<T>(_f: impl Fn() -> T) -> (Option<usize>, Option<usize>) {
+    (None, None)
+}
+
+impl Report {
+    /// The report as a single line of JSON.
+    ///
+    /// # Panics
+    /// Never, the report always serializes.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("A report always serializes")
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            let name = match step.name {
+                "part1" => "part 1",
+                "part2" => "part 2",
+                name => name,
+            };
+            write!(f, "Day {:02} {name:<6} took {:>10.3?}", self.day, step.time)?;
+            if let Some(peak_bytes) = step.peak_bytes {
+                write!(f, ", peak allocation {}", Bytes(peak_bytes))?;
+            }
+            if let Some(total_bytes) = step.total_bytes {
+                write!(f, ", {} allocated in total", Bytes(total_bytes))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A number of bytes, displayed in the largest binary unit it fills, with one decimal.
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut tenths = self.0 / 1024 * 10 + self.0 % 1024 * 10 / 1024;
+        let mut unit = 0;
+        while tenths >= 10240 && unit + 1 < UNITS.len() {
+            tenths /= 1024;
+            unit += 1;
+        }
+        write!(f, "{}.{} {}", tenths / 10, tenths % 10, UNITS[unit])
+    }
+}
+
+fn nanos<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(time.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_measures() {
+        let (output, step) = measure("parse", false, || 42);
+        assert_eq!(output, 42);
+        assert_eq!((step.name, step.peak_bytes, step.total_bytes), ("parse", None, None));
+    }
+
+    #[test]
+    fn it_tracks_allocations_in_a_second_run() {
+        let runs = std::cell::Cell::new(0);
+        let (output, step) = measure("part1", true, || {
+            runs.set(runs.get() + 1);
+            vec![0u8; 4096].len()
+        });
+        let tracked = cfg!(feature = "report");
+        assert_eq!((output, runs.get()), (4096, if tracked { 2 } else { 1 }));
+        assert_eq!((step.peak_bytes.is_some(), step.total_bytes.is_some()), (tracked, tracked));
+    }
+
+    #[test]
+    fn it_displays_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024 + 1).to_string(), "3.0 MiB");
+    }
+
+    #[test]
+    fn it_reports() {
+        let parse = Step { name: "parse", answer: None, expected: None, time: Duration::from_micros(1500), peak_bytes: Some(2048), total_bytes: Some(3072) };
+        let part1 = Step { name: "part1", answer: Some("7".to_owned()), expected: None, time: Duration::from_nanos(10), peak_bytes: None, total_bytes: None };
+        let report = Report { day: 1, steps: vec![parse, part1] };
+        assert_eq!(report.to_string(), "Day 01 parse  took    1.500ms, peak allocation 2.0 KiB, 3.0 KiB allocated in total\nDay 01 part 1 took   10.000ns\n");
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":1,"steps":[{"name":"parse","time_ns":1500000,"peak_bytes":2048,"total_bytes":3072},{"name":"part1","answer":"7","time_ns":10,"peak_bytes":null,"total_bytes":null}]}"#
+        );
+    }
+}
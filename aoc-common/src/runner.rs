@@ -0,0 +1,117 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+use clap::{Args, Parser};
+use color_eyre::eyre::bail;
+use color_eyre::Result;
+
+use crate::answers::{load_answers, Answers, Check};
+use crate::report::{measure, Report, ReportFormat, Step};
+use crate::{load_input, Solution};
+
+/// Command line arguments shared by every day's binary and `aoc run`.
+#[derive(Args, Clone, Debug, Default)]
+pub struct RunArgs {
+    /// Only solve this part instead of both
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Read the puzzle input from this file, or from stdin for `-`
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+    /// Compare the answers with the stored ones and fail if one is wrong
+    #[arg(long)]
+    pub check: bool,
+    /// Read the stored answers for `--check` from this file instead of the embedded ones
+    #[arg(long, requires = "check")]
+    pub answers: Option<PathBuf>,
+    /// Report the time of parsing and of each part, as `human` or `json`, and their peak and total allocation if built with the `report` feature
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "human")]
+    pub report: Option<ReportFormat>,
+}
+
+#[derive(Parser, Debug)]
+struct DayCli {
+    #[command(flatten)]
+    args: RunArgs,
+}
+
+/// Parses `input` and prints the answer of `part`, or of all solved parts if no part is given.
+///
+/// With `answers`, every answer is also compared with the stored one. With a `report` format, the
+/// time and peak allocation of parsing and of each part are printed as well; as JSON, they replace
+/// the answers.
+///
+/// # Errors
+/// If the input cannot be parsed, one of the parts fails, or an answer differs from the stored one.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>, answers: Option<&Answers>, report: Option<ReportFormat>) -> Result<()> {
+    let track_allocations = report.is_some();
+    let print_answers = report != Some(ReportFormat::Json);
+    let (input, parse) = measure("parse", track_allocations, || S::parse(input));
+    let input = input?;
+    let mut steps = vec![parse];
+    let mut wrong = Vec::new();
+    if part.unwrap_or(1) == 1 {
+        let (answer, mut step) = measure("part1", track_allocations, || S::part1(&input));
+        check::<S>(1, &answer?, &mut step, answers, print_answers, &mut wrong);
+        steps.push(step);
+    }
+    if part.map_or(S::PART2_SOLVED, |part| part == 2) {
+        let (answer, mut step) = measure("part2", track_allocations, || S::part2(&input));
+        check::<S>(2, &answer?, &mut step, answers, print_answers, &mut wrong);
+        steps.push(step);
+    }
+    let measurements = Report { day: S::DAY, steps };
+    match report {
+        None => {}
+        Some(ReportFormat::Human) => print!("{measurements}"),
+        Some(ReportFormat::Json) => println!("{}", measurements.to_json()),
+    }
+    match wrong.as_slice() {
+        [] => Ok(()),
+        [part] => bail!("Day {:02} part {part} is wrong", S::DAY),
+        _ => bail!("Day {:02} parts {} are wrong", S::DAY, wrong.iter().map(u8::to_string).collect::<Vec<_>>().join(" and ")),
+    }
+}
+
+/// Checks the `answer` of `part`, prints it and adds it to its `step`.
+fn check<S: Solution>(part: u8, answer: &impl Display, step: &mut Step, answers: Option<&Answers>, print: bool, wrong: &mut Vec<u8>) {
+    let check = answers.map(|answers| answers.check(part, answer));
+    let mark = match &check {
+        None => String::new(),
+        Some(Check::Correct) => " (correct)".to_owned(),
+        Some(Check::Unknown) => " (unknown)".to_owned(),
+        Some(Check::Wrong { expected }) => format!(" (wrong, expected {expected})"),
+    };
+    if print {
+        println!("Day {:02} part {part}: {answer}{mark}", S::DAY);
+    }
+    if matches!(check, Some(Check::Wrong { .. })) {
+        wrong.push(part);
+    }
+    step.answer = Some(answer.to_string());
+    step.expected = answers.and_then(|answers| answers.get(part)).map(str::to_owned);
+}
+
+/// Loads the input as requested by `args` and solves it, checking the answers with `--check`.
+///
+/// The `embedded_answers` are the answers to the `embedded_input`.
+///
+/// # Errors
+/// If the input or answers cannot be loaded, or the input cannot be solved correctly.
+pub fn run<S: Solution>(args: &RunArgs, embedded_input: Option<&'static str>, embedded_answers: Option<&'static str>) -> Result<()> {
+    let input = load_input(args.input.as_deref(), embedded_input)?;
+    let answers = args
+        .check
+        .then(|| load_answers(args.answers.as_deref(), args.input.as_deref(), embedded_answers))
+        .transpose()?;
+    solve::<S>(&input, args.part, answers.as_ref(), args.report)
+}
+
+/// The `main` of every day's binary.
+///
+/// # Errors
+/// If the input cannot be loaded or solved correctly.
+pub fn main<S: Solution>(embedded_input: Option<&'static str>, embedded_answers: Option<&'static str>) -> Result<()> {
+    color_eyre::install()?;
+    run::<S>(&DayCli::parse().args, embedded_input, embedded_answers)
+}
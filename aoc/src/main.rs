@@ -0,0 +1,53 @@
+use aoc_common::{run, RunArgs};
+use clap::{Parser, Subcommand};
+use color_eyre::Result;
+
+aoc_common::global_allocator!();
+
+struct Day {
+    input: Option<&'static str>,
+    answers: Option<&'static str>,
+    run: fn(&RunArgs, Option<&'static str>, Option<&'static str>) -> Result<()>,
+}
+
+macro_rules! days {
+    ($($day:ident::$solution:ident),* $(,)?) => {
+        [$(Day { input: $day::INPUT, answers: $day::ANSWERS, run: run::<$day::$solution> }),*]
+    };
+}
+
+const DAYS: [Day; 24] = days![
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11, day12::Day12,
+    day13::Day13, day14::Day14, day15::Day15, day16::Day16, day17::Day17, day18::Day18,
+    day19::Day19, day20::Day20, day21::Day21, day22::Day22, day23::Day23, day24::Day24,
+];
+
+#[derive(Parser, Debug)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solves the puzzle of one day
+    Run {
+        /// The day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=24))]
+        day: u8,
+        #[command(flatten)]
+        args: RunArgs,
+    },
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    match Cli::parse().command {
+        Command::Run { day, args } => {
+            let day = &DAYS[usize::from(day - 1)];
+            (day.run)(&args, day.input, day.answers)
+        }
+    }
+}
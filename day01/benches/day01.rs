@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day01::{Day01, INPUT};
+
+fn day01(c: &mut Criterion) {
+    bench::<Day01>(c, "example1", include_str!("../src/example1.txt"), &[1, 2]);
+    bench::<Day01>(c, "example2", include_str!("../src/example2.txt"), &[2]);
+    bench_input::<Day01>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day01);
+criterion_main!(benches);
@@ -0,0 +1,148 @@
+pub mod scanner;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use aoc_common::Solution;
+
+pub use crate::scanner::DigitScanner;
+use crate::scanner::ENGLISH;
+
+aoc_common::embedded_input!();
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        Ok(sum_of_calibration_values(input, &DigitScanner::numeric())?)
+    }
+
+    fn part2(input: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        Ok(sum_of_calibration_values(input, &DigitScanner::new(ENGLISH))?)
+    }
+}
+
+/// The calibration of a whole input, skipping the lines without a digit.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Calibration {
+    /// The sum of the calibration values of the lines with a digit, which are at most 99 each, so
+    /// that no input fitting in memory overflows it.
+    pub sum: u64,
+    /// The numbers of the lines without a digit, starting at 1.
+    pub skipped: Vec<usize>,
+}
+
+impl Calibration {
+    /// The sum, unless lines were skipped.
+    ///
+    /// # Errors
+    /// The skipped lines, if there are any.
+    pub fn strict(self) -> Result<u64, MissingDigits> {
+        if self.skipped.is_empty() {
+            Ok(self.sum)
+        } else {
+            Err(MissingDigits { lines: self.skipped })
+        }
+    }
+}
+
+/// The error of lines without a digit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingDigits {
+    /// The numbers of the lines without a digit, starting at 1.
+    pub lines: Vec<usize>,
+}
+
+impl Display for MissingDigits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.lines.as_slice() {
+            [line] => write!(f, "No digit found on line {line}"),
+            lines => write!(f, "No digit found on lines {}", lines.iter().map(usize::to_string).collect::<Vec<_>>().join(", ")),
+        }
+    }
+}
+
+impl Error for MissingDigits {}
+
+/// Calibrates every line of `input` with the digits found by `scanner`, leniently skipping the
+/// lines without a digit.
+#[must_use]
+pub fn calibrate(input: &str, scanner: &DigitScanner) -> Calibration {
+    let mut calibration = Calibration::default();
+    for (number, line) in input.lines().enumerate() {
+        match scanner.calibration_value(line) {
+            Some(value) => calibration.sum += u64::from(value),
+            None => calibration.skipped.push(number + 1),
+        }
+    }
+    calibration
+}
+
+/// The sum of the calibration values of every line of `input`, with the digits found by `scanner`.
+///
+/// # Errors
+/// The lines without a digit, if there are any.
+pub fn sum_of_calibration_values(input: &str, scanner: &DigitScanner) -> Result<u64, MissingDigits> {
+    calibrate(input, scanner).strict()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn it_gets_calibration_value() {
+        let scanner = DigitScanner::numeric();
+        let values: Vec<_> = EXAMPLE1.lines().map(|line| scanner.calibration_value(line)).collect();
+        assert_eq!(values, [Some(12), Some(38), Some(15), Some(77)]);
+    }
+
+    #[test]
+    fn it_gets_calibration_value_with_spelled_numbers() {
+        let scanner = DigitScanner::new(ENGLISH);
+        let values: Vec<_> = EXAMPLE2.lines().map(|line| scanner.calibration_value(line)).collect();
+        assert_eq!(values, [Some(29), Some(83), Some(13), Some(24), Some(42), Some(14), Some(76)]);
+    }
+
+    #[test]
+    fn it_gets_sum_with_spelled_numbers() {
+        assert_eq!(Ok(281), sum_of_calibration_values(EXAMPLE2, &DigitScanner::new(ENGLISH)));
+    }
+
+    #[test]
+    fn it_reports_lines_without_digits() {
+        let scanner = DigitScanner::numeric();
+        let err = sum_of_calibration_values(EXAMPLE2, &scanner).unwrap_err();
+        assert_eq!(err.lines, [2]);
+        assert_eq!(err.to_string(), "No digit found on line 2");
+        let err = sum_of_calibration_values("a\n1\n\nb", &scanner).unwrap_err();
+        assert_eq!(err.to_string(), "No digit found on lines 1, 3, 4");
+    }
+
+    #[test]
+    fn it_skips_lines_without_digits() {
+        let calibration = calibrate(EXAMPLE2, &DigitScanner::numeric());
+        assert_eq!(calibration, Calibration { sum: 11 + 22 + 33 + 42 + 24 + 77, skipped: vec![2] });
+        assert_eq!(calibrate("", &DigitScanner::numeric()).strict(), Ok(0));
+    }
+
+    #[test]
+    fn it_gets_very_short_numbers() {
+        assert_eq!(Some(77), DigitScanner::numeric().calibration_value("v7"));
+    }
+    #[test]
+    fn it_gets_overlapping_numbers() {
+        assert_eq!(Some(38), DigitScanner::new(ENGLISH).calibration_value("threeight"));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::VecDeque;
+
+/// The English digit words.
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+];
+
+/// The German digit words.
+pub const GERMAN: &[(&str, u32)] = &[
+    ("eins", 1), ("zwei", 2), ("drei", 3), ("vier", 4), ("fünf", 5), ("sechs", 6), ("sieben", 7), ("acht", 8), ("neun", 9),
+];
+
+/// The French digit words.
+pub const FRENCH: &[(&str, u32)] = &[
+    ("un", 1), ("deux", 2), ("trois", 3), ("quatre", 4), ("cinq", 5), ("six", 6), ("sept", 7), ("huit", 8), ("neuf", 9),
+];
+
+/// Finds digits, numeric or spelled out with a table of words, in a single pass over a line.
+///
+/// This is an [Aho-Corasick](https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm)
+/// automaton over the bytes of the words and the numeric digits: every byte of the line is one
+/// lookup in the transition table, and every state knows all words ending in it. That way
+/// overlapping words like "eightwo" are both found without backtracking or rewriting the line.
+#[derive(Clone, Debug)]
+pub struct DigitScanner {
+    /// `transitions[state][byte]` is the state after reading `byte` in `state`.
+    transitions: Vec<[usize; 256]>,
+    /// The length and digit of every word ending in a state, including the words it ends with.
+    matches: Vec<Vec<(usize, u32)>>,
+}
+
+impl DigitScanner {
+    /// A scanner of the numeric digits only.
+    #[must_use]
+    pub fn numeric() -> Self {
+        Self::new(&[])
+    }
+
+    /// A scanner of the numeric digits and the `words` spelling out digits.
+    ///
+    /// # Panics
+    /// If a word is empty or its digit is not a single digit.
+    #[must_use]
+    pub fn new(words: &[(&str, u32)]) -> Self {
+        const ROOT: usize = 0;
+        const NONE: usize = usize::MAX;
+        let numeric = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"].into_iter().zip(0..);
+        let mut transitions = vec![[NONE; 256]];
+        let mut matches = vec![vec![]];
+        for (word, digit) in numeric.chain(words.iter().copied()) {
+            assert!(!word.is_empty(), "The word of {digit} is empty");
+            assert!(digit < 10, "{digit} of {word} is not a single digit");
+            let mut state = ROOT;
+            for &byte in word.as_bytes() {
+                if transitions[state][usize::from(byte)] == NONE {
+                    transitions[state][usize::from(byte)] = transitions.len();
+                    transitions.push([NONE; 256]);
+                    matches.push(vec![]);
+                }
+                state = transitions[state][usize::from(byte)];
+            }
+            matches[state].push((word.len(), digit));
+        }
+
+        // breadth first, so the longest proper suffix of a state is complete before the state
+        let mut suffix = vec![ROOT; transitions.len()];
+        let mut queue = VecDeque::from([ROOT]);
+        while let Some(state) = queue.pop_front() {
+            if state != ROOT {
+                let inherited = matches[suffix[state]].clone();
+                matches[state].extend(inherited);
+            }
+            // a missing transition continues from the longest suffix instead
+            let fallbacks = if state == ROOT { [ROOT; 256] } else { transitions[suffix[state]] };
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                if *next == NONE {
+                    *next = fallback;
+                } else {
+                    suffix[*next] = fallback;
+                    queue.push_back(*next);
+                }
+            }
+        }
+        Self { transitions, matches }
+    }
+
+    /// The first and the last digit of `line`, ordered by where they start.
+    ///
+    /// Of two words starting at the same place, the shorter one counts.
+    #[must_use]
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut state = 0;
+        let mut first: Option<(usize, u32)> = None;
+        let mut last: Option<(usize, u32)> = None;
+        for (end, &byte) in line.as_bytes().iter().enumerate() {
+            state = self.transitions[state][usize::from(byte)];
+            for &(length, digit) in &self.matches[state] {
+                let start = end + 1 - length;
+                if first.is_none_or(|(first_start, _)| start < first_start) {
+                    first = Some((start, digit));
+                }
+                if last.is_none_or(|(last_start, _)| start > last_start) {
+                    last = Some((start, digit));
+                }
+            }
+        }
+        Some((first?.1, last?.1))
+    }
+
+    /// The calibration value of `line`: its first and last digit as a two digit number.
+    #[must_use]
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        self.first_and_last(line).map(|(first, last)| first * 10 + last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_finds_numeric_digits() {
+        let scanner = DigitScanner::numeric();
+        assert_eq!(scanner.first_and_last("a1b2c3"), Some((1, 3)));
+        assert_eq!(scanner.first_and_last("one7"), Some((7, 7)));
+        assert_eq!(scanner.first_and_last("none"), None);
+    }
+
+    #[test]
+    fn it_finds_overlapping_words() {
+        let scanner = DigitScanner::new(ENGLISH);
+        assert_eq!(scanner.calibration_value("eightwo"), Some(82));
+        assert_eq!(scanner.calibration_value("threeight"), Some(38));
+        assert_eq!(scanner.calibration_value("twone"), Some(21));
+        assert_eq!(scanner.calibration_value("oneighthree"), Some(13));
+        assert_eq!(scanner.calibration_value("sevenine"), Some(79));
+        assert_eq!(scanner.calibration_value("ninine"), Some(99));
+    }
+
+    #[test]
+    fn it_orders_nested_words_by_start() {
+        // "b" ends before "abc", but starts after it
+        let scanner = DigitScanner::new(&[("abc", 1), ("b", 2)]);
+        assert_eq!(scanner.first_and_last("abc"), Some((1, 2)));
+        let scanner = DigitScanner::new(&[("ab", 1), ("abc", 2)]);
+        assert_eq!(scanner.first_and_last("abc"), Some((1, 1)));
+    }
+
+    #[test]
+    fn it_uses_other_word_tables() {
+        assert_eq!(DigitScanner::new(GERMAN).calibration_value("xfünfzweinsx"), Some(51));
+        assert_eq!(DigitScanner::new(FRENCH).calibration_value("sixquatreuneuf"), Some(69));
+        assert_eq!(DigitScanner::new(ENGLISH).calibration_value("fünf"), None);
+    }
+}
@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day02::{Day02, INPUT};
+
+fn day02(c: &mut Criterion) {
+    bench::<Day02>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day02>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day02);
+criterion_main!(benches);
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use aoc_common::{parse, ParseError, Solution};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::map,
+    IResult, multi::separated_list1,
+    sequence::{separated_pair, tuple},
+};
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{alpha1, multispace0};
+use nom::combinator::map_res;
+use nom::sequence::delimited;
+
+aoc_common::embedded_input!();
+
+/// The colours of the cubes in the puzzle.
+pub const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+const PART1_BAG: [(&str, usize); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Game {
+    id: usize,
+    draws: Vec<Draw>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &COLOURS)
+    }
+}
+
+impl Game {
+    /// Parses a game like `Game 1: 3 blue, 4 red; 1 red, 2 green`, whose cubes can only have one of
+    /// the `colours`.
+    ///
+    /// # Errors
+    /// If the game is malformed or has a cube of another colour.
+    pub fn parse_with(s: &str, colours: &[&str]) -> Result<Self, ParseError> {
+        let (id, draws) = parse::complete(s, tuple((
+            delimited(tag_no_case("Game "), map_res(digit1, str::parse), tag(": ")),
+            separated_list1(tag("; "), cubes))
+        ))?;
+        if let Some(colour) = draws.iter().flatten().map(|(colour, _)| *colour).find(|colour| !colours.contains(colour)) {
+            return Err(ParseError::at(s, colour, "Unknown colour"));
+        }
+        Ok(Self { id, draws: draws.into_iter().map(Draw::new).collect() })
+    }
+
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    #[must_use]
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    /// Whether every draw of the game could have been drawn from `bag`.
+    #[must_use]
+    pub fn is_possible_with(&self, bag: &Draw) -> bool {
+        self.draws.iter().all(|draw| draw.fits_in(bag))
+    }
+
+    /// The smallest bag every draw of the game could have been drawn from.
+    #[must_use]
+    pub fn minimum_bag(&self) -> Draw {
+        minimum_bag([self])
+    }
+}
+
+/// A handful of cubes by colour, either drawn in a game or the content of a bag.
+///
+/// Draws are partially ordered by dominance: a draw is less than another if it has at most as many
+/// cubes of every colour, and fewer of one. Draws with more cubes of one colour and fewer of another
+/// are incomparable.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Draw {
+    /// Only the colours with cubes, so draws with the same cubes are equal.
+    cubes: BTreeMap<String, usize>,
+}
+
+impl Draw {
+    /// The draw of `cubes`, adding up the amounts of the same colour.
+    pub fn new<C: Into<String>>(cubes: impl IntoIterator<Item = (C, usize)>) -> Self {
+        let mut draw = Self::default();
+        for (colour, amount) in cubes {
+            *draw.cubes.entry(colour.into()).or_default() += amount;
+        }
+        draw.cubes.retain(|_, amount| *amount > 0);
+        draw
+    }
+
+    /// Whether the draw could have been drawn from `bag`.
+    #[must_use]
+    pub fn fits_in(&self, bag: &Self) -> bool {
+        self <= bag
+    }
+
+    /// The smallest draw both this and `other` fit in: the most cubes of every colour of either.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for (colour, &amount) in &other.cubes {
+            let most = union.cubes.entry(colour.clone()).or_default();
+            *most = (*most).max(amount);
+        }
+        union
+    }
+
+    /// The amount of cubes of `colour`.
+    #[must_use]
+    pub fn get(&self, colour: &str) -> usize {
+        self.cubes.get(colour).copied().unwrap_or_default()
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+
+    /// The amounts of all `colours` multiplied together.
+    #[must_use]
+    pub fn power(&self, colours: &[&str]) -> usize {
+        colours.iter().map(|colour| self.get(colour)).product()
+    }
+}
+
+/// Parses a draw like `3 blue, 4 red`, of any colours.
+impl FromStr for Draw {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, cubes).map(Self::new)
+    }
+}
+
+impl Display for Draw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self.cubes.iter().map(|(colour, amount)| format!("{amount} {colour}")).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl PartialOrd for Draw {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut ordering = Ordering::Equal;
+        for colour in self.colours().chain(other.colours()) {
+            match (ordering, self.get(colour).cmp(&other.get(colour))) {
+                (_, Ordering::Equal) => {}
+                (Ordering::Equal, colour_ordering) => ordering = colour_ordering,
+                (ordering, colour_ordering) if ordering == colour_ordering => {}
+                _ => return None,
+            }
+        }
+        Some(ordering)
+    }
+}
+
+fn cubes(input: &str) -> IResult<&str, Vec<(&str, usize)>> {
+    separated_list1(
+        tag(", "),
+        map(separated_pair(map_res(digit1, str::parse), multispace0, alpha1), |(amount, colour)| (colour, amount)),
+    )(input)
+}
+
+/// The games that are possible with `bag`.
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Draw) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(|game| game.is_possible_with(bag))
+}
+
+/// The smallest bag all `games` are possible with.
+pub fn minimum_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Draw {
+    games.into_iter().flat_map(|game| &game.draws).fold(Draw::default(), |bag, draw| bag.union(draw))
+}
+
+/// Parses the games of `input`, whose cubes can only have one of the `colours`.
+///
+/// # Errors
+/// If a game is malformed or has a cube of another colour.
+pub fn parse_games(input: &str, colours: &[&str]) -> Result<Vec<Game>, ParseError> {
+    input.lines().map(|line| Game::parse_with(line, colours).map_err(|e| e.within(input, line))).collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse::parse_lines(input)?)
+    }
+
+    fn part1(games: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        Ok(possible_games(games, &Draw::new(PART1_BAG)).map(Game::id).sum())
+    }
+
+    fn part2(games: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        Ok(games.iter().map(|game| game.minimum_bag().power(&COLOURS)).sum())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example.txt");
+
+    #[test]
+    fn it_parses_draw() {
+        let expected = Draw::new([("red", 2), ("green", 12), ("blue", 1)]);
+        assert_eq!(expected, "1 blue, 12 green, 2 red".parse().unwrap());
+        assert_eq!(Draw::new([("purple", 3)]), "1 purple, 2 purple".parse().unwrap());
+        assert_eq!(expected.to_string(), "1 blue, 12 green, 2 red");
+    }
+
+    #[test]
+    fn it_parses_game() {
+        let game = Game {
+            id: 5,
+            draws: vec![
+                Draw::new([("red", 6), ("green", 3), ("blue", 1)]),
+                Draw::new([("red", 1), ("green", 2), ("blue", 2)]),
+            ],
+        };
+        assert_eq!(game, "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".parse().unwrap());
+    }
+
+    #[test]
+    fn it_rejects_malformed_game() {
+        let err = "Game 5: 6 red; x blue".parse::<Game>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 14, "; x blue"));
+    }
+
+    #[test]
+    fn it_reports_unknown_colours() {
+        let err = "Game 5: 6 red; 2 purple, 1 blue".parse::<Game>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 18, "purple"));
+        let game = Game::parse_with("Game 5: 6 red; 2 purple, 1 blue", &["red", "blue", "purple"]).unwrap();
+        assert_eq!(game.minimum_bag(), Draw::new([("red", 6), ("purple", 2), ("blue", 1)]));
+    }
+
+    #[test]
+    fn it_orders_draws_by_dominance() {
+        let small = Draw::new([("red", 1), ("blue", 2)]);
+        let large = Draw::new([("red", 1), ("blue", 3), ("green", 1)]);
+        let other = Draw::new([("red", 2)]);
+        assert_eq!(small.partial_cmp(&small.clone()), Some(Ordering::Equal));
+        assert!(small < large);
+        assert!(large > small);
+        assert_eq!(small.partial_cmp(&other), None);
+        assert_eq!(other.partial_cmp(&small), None);
+        assert!(!small.le(&other));
+        assert!(!small.ge(&other));
+        assert_eq!(Draw::new([("red", 0)]), Draw::default());
+        assert_eq!(Draw::default().partial_cmp(&small), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn it_fits_and_unites_draws() {
+        let small = Draw::new([("red", 1), ("blue", 2)]);
+        let other = Draw::new([("red", 2)]);
+        assert!(small.fits_in(&small));
+        assert!(!small.fits_in(&other));
+        let union = small.union(&other);
+        assert_eq!(union, Draw::new([("red", 2), ("blue", 2)]));
+        assert!(small.fits_in(&union) && other.fits_in(&union));
+        assert_eq!(union, other.union(&small));
+    }
+
+    #[test]
+    fn it_solves_example_part1() {
+        let games = parse_games(EXAMPLE1, &COLOURS).unwrap();
+        let ids: Vec<usize> = possible_games(&games, &Draw::new(PART1_BAG)).map(Game::id).collect();
+        assert_eq!(ids, [1, 2, 5]);
+    }
+
+    #[test]
+    fn it_solves_example_part2() {
+        let games = parse_games(EXAMPLE1, &COLOURS).unwrap();
+        assert_eq!(2286_usize, games.iter().map(|game| game.minimum_bag().power(&COLOURS)).sum::<usize>());
+    }
+
+    #[test]
+    fn it_gets_minimum_bag_of_games() {
+        let games = parse_games(EXAMPLE1, &COLOURS).unwrap();
+        let bag = minimum_bag(&games);
+        assert_eq!(bag, Draw::new([("red", 20), ("green", 13), ("blue", 15)]));
+        assert_eq!(possible_games(&games, &bag).count(), games.len());
+        assert_eq!(minimum_bag(&games[..0]), Draw::default());
+    }
+}
@@ -1,155 +1,52 @@
-use std::cmp::Ordering;
-
-use nom::{
-    bytes::complete::tag,
-    character::complete::digit1,
-    combinator::{all_consuming, map},
-    Finish,
-    IResult, multi::separated_list1,
-    sequence::{separated_pair, tuple},
-};
-use nom::bytes::complete::tag_no_case;
-use nom::character::complete::{alpha1, multispace0};
-use nom::combinator::map_res;
-use nom::sequence::delimited;
-
-const INPUT: &str = include_str!("input.txt");
-const PART1_BAG: Draw = Draw {
-    red: 12,
-    green: 13,
-    blue: 14,
-};
-
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
-struct Game {
-    id: usize,
-    draws: Vec<Draw>,
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::{load_input, parse, run, RunArgs};
+use clap::Parser;
+use color_eyre::eyre::WrapErr;
+use day02::{minimum_bag, parse_games, possible_games, Day02, Draw, ANSWERS, COLOURS, INPUT};
+
+aoc_common::global_allocator!();
+
+#[derive(Parser, Debug)]
+struct Cli {
+    #[command(flatten)]
+    args: RunArgs,
+    /// List the games possible with this bag, like `12 red, 13 green, 14 blue`, instead of solving the puzzle
+    #[arg(long)]
+    bag: Vec<String>,
+    /// List the games possible with each bag in this file, one per line, instead of solving the puzzle
+    #[arg(long)]
+    bags: Option<PathBuf>,
+    /// Print the smallest bag all games are possible with instead of solving the puzzle
+    #[arg(long)]
+    minimum_bag: bool,
 }
 
-impl Game {
-    fn parse(input: &str) -> Self {
-        all_consuming(
-            map(tuple((
-                delimited(tag_no_case("Game "), map_res(digit1, str::parse), tag(": ")),
-                separated_list1(tag("; "), Draw::parse))
-            ), |(id, draws)| Self { id, draws: draws.into_iter().map(Draw::from).collect() })
-        )(input).finish().unwrap().1
+fn main() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+    let cli = Cli::parse();
+    if cli.bag.is_empty() && cli.bags.is_none() && !cli.minimum_bag {
+        return run::<Day02>(&cli.args, INPUT, ANSWERS);
     }
 
-    fn get_power_of_minimum_set(&self) -> usize {
-        self.draws.iter().fold(Draw::default(), |acc, draw| Draw {
-            red: acc.red.max(draw.red),
-            green: acc.green.max(draw.green),
-            blue: acc.blue.max(draw.blue),
-        }).power()
-    }
-}
-
-#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
-struct Draw {
-    red: usize,
-    green: usize,
-    blue: usize,
-}
-
-
-impl Draw {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        map(separated_list1(
-            tag(", "),
-            separated_pair(map_res(digit1, str::parse),
-                           multispace0,
-                           alpha1)), |x: Vec<(usize, &str)>| {
-            let mut s = Self::default();
-            for (amount, colour) in x {
-                match colour {
-                    "red" => s.red += amount,
-                    "green" => s.green += amount,
-                    "blue" => s.blue += amount,
-                    _ => ()
-                }
-            }
-            s
-        })(input)
+    let mut bags = cli.bag.iter().map(|bag| bag.parse()).collect::<Result<Vec<Draw>, _>>()?;
+    if let Some(path) = &cli.bags {
+        let text = fs::read_to_string(path).wrap_err_with(|| format!("Cannot read bags {}", path.display()))?;
+        bags.extend(parse::parse_lines::<Draw>(&text)?);
     }
+    // the games may draw any colour one of the bags has
+    let mut colours = COLOURS.to_vec();
+    colours.extend(bags.iter().flat_map(Draw::colours).filter(|colour| !COLOURS.contains(colour)));
 
-    const fn power(&self) -> usize {
-        self.red * self.green * self.blue
+    let input = load_input(cli.args.input.as_deref(), INPUT)?;
+    let games = parse_games(&input, &colours)?;
+    for bag in &bags {
+        let ids: Vec<String> = possible_games(&games, bag).map(|game| game.id().to_string()).collect();
+        println!("{bag}: {}", if ids.is_empty() { "no games".to_owned() } else { format!("games {}", ids.join(", ")) });
     }
-}
-
-impl PartialOrd for Draw {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.red > other.red || self.green > other.green || self.blue > other.blue {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Less)
-        }
+    if cli.minimum_bag {
+        println!("Minimum bag: {}", minimum_bag(&games));
     }
+    Ok(())
 }
-
-fn main() {
-    let games: Vec<Game> = INPUT.lines().map(Game::parse).collect();
-
-    let sum: usize = games
-        .iter()
-        .filter(|g| g.draws.iter().all(|draw| draw < &PART1_BAG))
-        .map(|game| game.id)
-        .sum();
-    println!("Day 02 part 1: {sum}");
-    println!("Day 02 part 2: {}", games.iter().map(Game::get_power_of_minimum_set).sum::<usize>());
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE1: &str = include_str!("example.txt");
-
-    #[test]
-    fn it_parses_draw() {
-        let expected = Draw {
-            red: 2,
-            green: 12,
-            blue: 1,
-        };
-        assert_eq!(expected, Draw::parse("1 blue, 12 green, 2 red").unwrap().1);
-    }
-
-    #[test]
-    fn it_parses_game() {
-        let game = Game {
-            id: 5,
-            draws: vec![Draw {
-                red: 6,
-                green: 3,
-                blue: 1,
-            }, Draw {
-                red: 1,
-                green: 2,
-                blue: 2,
-            }],
-        };
-        assert_eq!(game, Game::parse("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"));
-    }
-
-
-    #[test]
-    fn it_solves_example_part1() {
-        let games: Vec<Game> = EXAMPLE1.lines().map(Game::parse).collect();
-
-        let sum: usize = games
-            .iter()
-            .filter(|g| g.draws.iter().all(|draw| draw < &PART1_BAG))
-            .map(|game| game.id)
-            .sum();
-        assert_eq!(8, sum);
-    }
-
-    #[test]
-    fn it_solves_example_part2() {
-        let games: Vec<Game> = EXAMPLE1.lines().map(Game::parse).collect();
-        assert_eq!(2286_usize, games.iter().map(Game::get_power_of_minimum_set).sum());
-    }
-}
\ No newline at end of file
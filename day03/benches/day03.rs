@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day03::{Day03, INPUT};
+
+fn day03(c: &mut Criterion) {
+    bench::<Day03>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day03>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day03);
+criterion_main!(benches);
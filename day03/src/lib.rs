@@ -0,0 +1,286 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use aoc_common::grid::Grid;
+use aoc_common::{ParseError, Solution};
+
+aoc_common::embedded_input!();
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(schematic: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        Ok(schematic.part_numbers().map(|number| number.value).sum())
+    }
+
+    fn part2(schematic: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        Ok(schematic.gears().map(|(_, numbers)| numbers.iter().map(|number| number.value).product::<usize>()).sum())
+    }
+}
+
+/// A number in a schematic, spanning `length` cells of row `y` from column `x`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Number {
+    pub value: usize,
+    pub x: usize,
+    pub y: usize,
+    pub length: usize,
+}
+
+/// A symbol in a schematic: any character but a digit or `.`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Symbol {
+    pub symbol: char,
+    pub x: usize,
+    pub y: usize,
+}
+
+/// What is drawn on a cell of a schematic, indexing into [`Schematic::numbers`] or
+/// [`Schematic::symbols`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+/// An engine schematic: the numbers and symbols drawn on a grid, and which of them are adjacent.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Schematic {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    cells: Grid<Cell>,
+}
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    /// Parses a schematic, tolerating `\r\n` line endings, trailing whitespace and blank lines
+    /// around it, and rows of different lengths, which are padded with `.` to the longest row.
+    ///
+    /// # Errors
+    /// If the schematic is empty, or a row contains whitespace or control characters.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.trim_start_matches(['\r', '\n']).trim_end().lines().map(str::trim_end).collect();
+        for row in &lines {
+            if let Some((i, c)) = row.char_indices().find(|(_, c)| c.is_whitespace() || c.is_control()) {
+                return Err(ParseError::at(s, &row[i..i + c.len_utf8()], format!("Unexpected {c:?} in a schematic row")));
+            }
+        }
+        let rows: Vec<Vec<char>> = lines.iter().map(|row| row.chars().collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+        if width == 0 {
+            return Err(ParseError::new("Cannot parse an empty schematic", s));
+        }
+        let grid = Grid::from_fn(width, rows.len(), |(x, y)| rows[y].get(x).copied().unwrap_or('.'));
+        let mut numbers: Vec<Number> = vec![];
+        let mut symbols = vec![];
+        let mut cells = grid.map(|_| Cell::Empty);
+        for ((x, y), &c) in grid.indexed_iter() {
+            if let Some(digit) = c.to_digit(10) {
+                let digit = digit as usize;
+                // a digit continues the number on its left, if there is one
+                if let Some(Cell::Number(i)) = x.checked_sub(1).map(|left| cells[(left, y)]) {
+                    numbers[i].value = numbers[i].value.checked_mul(10).and_then(|value| value.checked_add(digit)).ok_or_else(|| {
+                        let (start, _) = lines[y].char_indices().nth(numbers[i].x).unwrap_or_default();
+                        let number = lines[y][start..].split(|c: char| !c.is_ascii_digit()).next().unwrap_or_default();
+                        ParseError::at(s, number, "The number is too large")
+                    })?;
+                    numbers[i].length += 1;
+                    cells[(x, y)] = Cell::Number(i);
+                } else {
+                    cells[(x, y)] = Cell::Number(numbers.len());
+                    numbers.push(Number { value: digit, x, y, length: 1 });
+                }
+            } else if c != '.' {
+                cells[(x, y)] = Cell::Symbol(symbols.len());
+                symbols.push(Symbol { symbol: c, x, y });
+            }
+        }
+        Ok(Self { numbers, symbols, cells })
+    }
+}
+
+impl Schematic {
+    /// All numbers, row by row.
+    #[must_use]
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// All symbols, row by row.
+    #[must_use]
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The distinct numbers with a digit next to `symbol`, also diagonally.
+    #[must_use]
+    pub fn numbers_adjacent_to(&self, symbol: &Symbol) -> Vec<&Number> {
+        let indices = self.adjacent(symbol.x..=symbol.x, symbol.y, |cell| match cell {
+            Cell::Number(i) => Some(i),
+            _ => None,
+        });
+        indices.into_iter().map(|i| &self.numbers[i]).collect()
+    }
+
+    /// The symbols next to a digit of `number`, also diagonally.
+    #[must_use]
+    pub fn symbols_adjacent_to(&self, number: &Number) -> Vec<&Symbol> {
+        let indices = self.adjacent(number.x..=number.x + number.length - 1, number.y, |cell| match cell {
+            Cell::Symbol(i) => Some(i),
+            _ => None,
+        });
+        indices.into_iter().map(|i| &self.symbols[i]).collect()
+    }
+
+    /// The numbers adjacent to any symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers.iter().filter(|number| !self.symbols_adjacent_to(number).is_empty())
+    }
+
+    /// The symbols `symbol` adjacent to exactly `count` numbers, with those numbers.
+    pub fn symbols_with_numbers(&self, symbol: char, count: usize) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .filter(move |s| s.symbol == symbol)
+            .map(|s| (s, self.numbers_adjacent_to(s)))
+            .filter(move |(_, numbers)| numbers.len() == count)
+    }
+
+    /// The gears: every `*` adjacent to exactly two numbers, with those numbers.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols_with_numbers('*', 2)
+    }
+
+    /// The distinct indices `index` finds in the cells around the columns `xs` of row `y`.
+    fn adjacent(&self, xs: RangeInclusive<usize>, y: usize, index: impl Fn(Cell) -> Option<usize>) -> Vec<usize> {
+        let mut indices: Vec<usize> = xs
+            .flat_map(|x| self.cells.neighbours8((x, y)))
+            .filter_map(|coordinate| index(self.cells[coordinate]))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example.txt");
+
+    fn part_numbers(input: &str) -> Vec<usize> {
+        input.parse::<Schematic>().unwrap().part_numbers().map(|number| number.value).collect()
+    }
+
+    #[test]
+    fn it_gets_numbers() {
+        let schematic: Schematic = "467..114..\n...*......".parse().unwrap();
+        let numbers: Vec<_> = schematic.numbers().iter().map(|number| (number.value, number.x, number.length)).collect();
+        assert_eq!(numbers, [(467, 0, 3), (114, 5, 3)]);
+        assert_eq!(schematic.symbols(), [Symbol { symbol: '*', x: 3, y: 1 }]);
+        assert_eq!(part_numbers("467..114..\n...*......"), [467]);
+    }
+
+    #[test]
+    fn it_gets_numbers_with_symbols() {
+        assert_eq!(4361_usize, part_numbers(EXAMPLE1).iter().sum::<usize>());
+    }
+
+    #[test]
+    fn it_gets_numbers_separated_by_symbol() {
+        assert_eq!(part_numbers("467#114"), [467, 114]);
+    }
+
+    #[test]
+    fn it_gets_single_number() {
+        assert!(part_numbers("123").is_empty());
+        assert_eq!(part_numbers("123#"), [123]);
+        assert_eq!(part_numbers("#123"), [123]);
+    }
+
+    #[test]
+    fn it_gets_asymmetric_file() {
+        assert!(part_numbers("123\n...").is_empty());
+    }
+
+    #[test]
+    fn it_gets_asymmetric_file_with_symbol() {
+        assert_eq!(part_numbers("..#\n123"), [123]);
+    }
+
+    #[test]
+    fn it_gets_separate_numbers() {
+        assert_eq!(part_numbers("123\n12#"), [123, 12]);
+        let schematic: Schematic = "12\n34".parse().unwrap();
+        assert_eq!(schematic.numbers().len(), 2);
+    }
+
+    #[test]
+    fn it_normalises_input() {
+        let expected: Schematic = "..#\n123".parse().unwrap();
+        assert_eq!("..#\r\n123\r\n".parse(), Ok(expected.clone()));
+        assert_eq!("..#  \n123\t\n\n".parse(), Ok(expected.clone()));
+        assert_eq!("\n..#\n123".parse(), Ok(expected));
+        let windows = EXAMPLE1.replace('\n', "\r\n").parse::<Schematic>().unwrap();
+        assert_eq!(windows.part_numbers().map(|number| number.value).sum::<usize>(), 4361);
+    }
+
+    #[test]
+    fn it_pads_ragged_rows() {
+        let schematic: Schematic = "467\n...*\n\n..35".parse().unwrap();
+        assert_eq!(schematic.numbers().iter().map(|number| (number.value, number.y)).collect::<Vec<_>>(), [(467, 0), (35, 3)]);
+        assert_eq!(part_numbers("467\n...*\n\n..35"), [467]);
+        assert_eq!(part_numbers("1\n#\n..35"), [1]);
+    }
+
+    #[test]
+    fn it_rejects_malformed_schematics() {
+        let error = "".parse::<Schematic>().unwrap_err();
+        assert_eq!(error.message, "Cannot parse an empty schematic");
+        assert!(" \r\n\n".parse::<Schematic>().is_err());
+        let error = "467..\n.1 2.".parse::<Schematic>().unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 3, "Unexpected ' ' in a schematic row"));
+        let error = "4\r67".parse::<Schematic>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+    }
+
+    #[test]
+    fn it_rejects_numbers_too_large() {
+        let error = "..*\n.123456789012345678901234567890*".parse::<Schematic>().unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 2, "The number is too large"));
+        assert_eq!(error.text, "123456789012345678901234567890");
+        let largest = format!("{}*", usize::MAX);
+        assert_eq!(part_numbers(&largest), [usize::MAX]);
+    }
+
+    #[test]
+    fn it_queries_adjacency() {
+        let schematic: Schematic = EXAMPLE1.parse().unwrap();
+        let plus = schematic.symbols().iter().find(|symbol| symbol.symbol == '+').unwrap();
+        let numbers: Vec<usize> = schematic.numbers_adjacent_to(plus).iter().map(|number| number.value).collect();
+        assert_eq!(numbers, [592]);
+        let number = schematic.numbers().iter().find(|number| number.value == 617).unwrap();
+        let symbols: Vec<char> = schematic.symbols_adjacent_to(number).iter().map(|symbol| symbol.symbol).collect();
+        assert_eq!(symbols, ['*']);
+        assert_eq!(schematic.symbols_with_numbers('*', 1).count(), 1);
+    }
+
+    #[test]
+    fn it_finds_gear_rations() {
+        let schematic: Schematic = EXAMPLE1.parse().unwrap();
+        let ratios: Vec<usize> = schematic.gears().map(|(_, numbers)| numbers[0].value * numbers[1].value).collect();
+        assert_eq!(ratios, [16345, 451_490]);
+    }
+}
@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day04::{Day04, INPUT};
+
+fn day04(c: &mut Criterion) {
+    bench::<Day04>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day04>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day04);
+criterion_main!(benches);
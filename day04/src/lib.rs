@@ -0,0 +1,179 @@
+pub mod simulation;
+
+use std::str::FromStr;
+use aoc_common::{parse, ParseError, Solution};
+use color_eyre::eyre::eyre;
+use nom::{bytes::complete::{tag, tag_no_case}, character::complete::{space0, space1, digit1}, combinator::map, IResult, multi::fold_many1, sequence::{delimited, separated_pair, tuple}};
+use nom::combinator::map_res;
+use nom::error::Error;
+use nom::sequence::preceded;
+
+pub use crate::simulation::{Cascade, Simulation};
+
+aoc_common::embedded_input!();
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse::parse_lines(input.trim())?)
+    }
+
+    fn part1(cards: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        cards.iter().map(|card| card.get_points().ok_or_else(|| eyre!("Card {} is worth too many points", card.id))).sum()
+    }
+
+    fn part2(cards: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        Ok(Simulation::run(cards, None)?.total())
+    }
+}
+
+/// A scratchcard, with its numbers as sets.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Card {
+    id: usize,
+    winning_numbers: NumberSet,
+    numbers: NumberSet,
+}
+
+impl Card {
+    /// The number of the card, as written on it.
+    #[must_use]
+    pub const fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The points the card is worth, doubling with every match after the first, or `None` if they
+    /// do not fit in a `usize`.
+    fn get_points(&self) -> Option<usize> {
+        let matches = u32::try_from(self.count_wins()).ok()?;
+        matches.checked_sub(1).map_or(Some(0), |doublings| 2usize.checked_pow(doublings))
+    }
+    const fn count_wins(&self) -> usize {
+        self.winning_numbers.intersection(self.numbers).len()
+    }
+}
+
+/// A set of the numbers on a card, as a bitset of every `u8`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+struct NumberSet([u128; 2]);
+
+impl NumberSet {
+    fn insert(&mut self, number: u8) {
+        self.0[usize::from(number / 128)] |= 1 << (number % 128);
+    }
+
+    const fn intersection(self, other: Self) -> Self {
+        Self([self.0[0] & other.0[0], self.0[1] & other.0[1]])
+    }
+
+    const fn len(self) -> usize {
+        (self.0[0].count_ones() + self.0[1].count_ones()) as usize
+    }
+}
+
+impl FromIterator<u8> for NumberSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = Self::default();
+        iter.into_iter().for_each(|number| set.insert(number));
+        set
+    }
+}
+
+
+fn parse_card(input: &str) -> IResult<&str, Card> {
+    map(tuple((
+        delimited(tuple((tag_no_case("Card"), space1)), map_res(digit1::<_, Error<_>>, str::parse::<usize>), tuple((tag_no_case(":"), space1))),
+        separated_pair(
+            number_set,
+            tuple((space1, tag("|"), space1)),
+            number_set,
+        ),
+    )), |(id, (winning_numbers, numbers))| Card { id, winning_numbers, numbers })(input)
+}
+
+fn number_set(input: &str) -> IResult<&str, NumberSet> {
+    fold_many1(preceded(space0, map_res(digit1, str::parse::<u8>)), NumberSet::default, |mut set, number| {
+        set.insert(number);
+        set
+    })(input)
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, parse_card)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_card() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let expected = Card {
+            id: 1,
+            winning_numbers: [41, 48, 83, 86, 17].into_iter().collect(),
+            numbers: [83, 86, 6, 31, 17, 9, 48, 53].into_iter().collect(),
+        };
+        let actual: Card = input.parse().unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_rejects_malformed_card() {
+        let err = "Card 1: 41 48 | 83 x".parse::<Card>().unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 19, " x"));
+        let err = parse::parse_lines::<Card>("Card 1: 1 | 2\nCard 2 1 | 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+    }
+
+    #[test]
+    fn it_keeps_card_ids() {
+        let cards: Vec<Card> = parse::parse_lines("Card   7: 1 | 2\nCard 12: 255 0 | 0 255 128").unwrap();
+        assert_eq!(cards.iter().map(Card::id).collect::<Vec<_>>(), [7, 12]);
+        assert_eq!(cards[1].count_wins(), 2);
+        assert!("Card 1: 256 | 1".parse::<Card>().is_err());
+    }
+
+    #[test]
+    fn it_gets_points() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let actual: Card = input.parse().unwrap();
+        assert_eq!(Some(8), actual.get_points());
+    }
+
+    #[test]
+    fn it_rejects_too_many_points() {
+        let card = |matches: u8| {
+            let numbers = (0..matches).map(|number| number.to_string()).collect::<Vec<_>>().join(" ");
+            format!("Card 1: {numbers} | {numbers}").parse::<Card>().unwrap()
+        };
+        assert_eq!(card(64).get_points(), Some(1 << 63));
+        assert_eq!(card(65).get_points(), None);
+        assert_eq!(card(200).get_points(), None);
+        assert!(Day04::part1(&vec![card(65)]).is_err());
+    }
+
+    #[test]
+    fn it_gets_all_points() {
+        let input = include_str!("example.txt");
+        let cards: Vec<Card> = input.trim().lines().map(|line| line.parse().unwrap()).collect();
+        assert_eq!(cards.iter().map(|card| card.get_points().unwrap()).sum::<usize>(), 13usize);
+    }
+
+    #[test]
+    fn it_counts_total_cards() {
+        let input = include_str!("example.txt");
+        let cards: Vec<Card> = input.trim().lines().map(|line| line.parse().unwrap()).collect();
+        assert_eq!(Simulation::run(&cards, None).unwrap().total(), 30);
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::Card;
+
+/// The outcome of playing a pile of scratchcards, remembering where every copy came from.
+///
+/// Cards are identified by their id, the number written on them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Simulation {
+    /// The index in the pile of the card with every id.
+    indices: HashMap<usize, usize>,
+    /// The instances of every card, the original included.
+    copies: Vec<usize>,
+    /// For every card, the ids of the cards it won copies from and how many, in order.
+    sources: Vec<Vec<(usize, usize)>>,
+}
+
+impl Simulation {
+    /// Plays every instance of `cards` in order, where each card wins one copy of each of the
+    /// cards after it for each of its matching numbers.
+    ///
+    /// With a `cap`, no card gets more than `cap` instances: copies won beyond it are lost, and
+    /// the cards they would have won are not played.
+    ///
+    /// # Errors
+    /// If two cards have the same id.
+    pub fn run(cards: &[Card], cap: Option<usize>) -> Result<Self> {
+        let mut indices = HashMap::with_capacity(cards.len());
+        for (index, card) in cards.iter().enumerate() {
+            if indices.insert(card.id, index).is_some() {
+                return Err(eyre!("There are several cards {}", card.id));
+            }
+        }
+        let cap = cap.unwrap_or(usize::MAX);
+        let mut copies = vec![1.min(cap); cards.len()];
+        let mut sources = vec![vec![]; cards.len()];
+        for (index, card) in cards.iter().enumerate() {
+            let instances = copies[index];
+            let won = (index + 1..cards.len()).take(card.count_wins());
+            for next in won {
+                let gain = instances.min(cap - copies[next]);
+                if gain > 0 {
+                    copies[next] += gain;
+                    sources[next].push((card.id, gain));
+                }
+            }
+        }
+        Ok(Self { indices, copies, sources })
+    }
+
+    /// The instances of all cards, the originals included.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.copies.iter().sum()
+    }
+
+    /// The instances of the card with `id`, the original included, or `None` if there is no such
+    /// card.
+    #[must_use]
+    pub fn instances(&self, id: usize) -> Option<usize> {
+        self.indices.get(&id).map(|&index| self.copies[index])
+    }
+
+    /// The copies won of the card with `id`, without the original, or `None` if there is no such
+    /// card.
+    #[must_use]
+    pub fn won(&self, id: usize) -> Option<usize> {
+        self.instances(id).map(|instances| instances.saturating_sub(1))
+    }
+
+    /// The ids of the cards the card with `id` won copies from, with how many copies each, in
+    /// order, or `None` if there is no such card.
+    #[must_use]
+    pub fn sources(&self, id: usize) -> Option<&[(usize, usize)]> {
+        self.indices.get(&id).map(|&index| self.sources[index].as_slice())
+    }
+}
+
+/// Every card won by playing a single instance of a card, and by playing the cards it won.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cascade {
+    /// The number written on the card.
+    pub id: usize,
+    pub won: Vec<Self>,
+}
+
+impl Cascade {
+    /// The cascade of playing the card with `id` of `cards`, or `None` if no card or several
+    /// cards have `id`.
+    ///
+    /// The tree grows exponentially with the number of wins, so this is for explaining small
+    /// inputs: use [`Simulation`] to count the cards of large ones.
+    #[must_use]
+    pub fn of(cards: &[Card], id: usize) -> Option<Self> {
+        let mut indices = cards.iter().enumerate().filter(|(_, card)| card.id == id).map(|(index, _)| index);
+        match (indices.next(), indices.next()) {
+            (Some(index), None) => Some(Self::at(cards, index)),
+            _ => None,
+        }
+    }
+
+    fn at(cards: &[Card], index: usize) -> Self {
+        let won = (index + 1..cards.len()).take(cards[index].count_wins()).map(|next| Self::at(cards, next)).collect();
+        Self { id: cards[index].id, won }
+    }
+
+    /// The instances in the tree, the played card included.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        1 + self.won.iter().map(Self::size).sum::<usize>()
+    }
+
+    fn fmt_indented(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(f, "{:indent$}Card {}", "", self.id, indent = depth * 2)?;
+        self.won.iter().try_for_each(|won| won.fmt_indented(f, depth + 1))
+    }
+}
+
+impl Display for Cascade {
+    /// One card per line, indented below the card that won it.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse;
+
+    use super::*;
+
+    fn example() -> Vec<Card> {
+        parse::parse_lines(include_str!("example.txt")).unwrap()
+    }
+
+    #[test]
+    fn it_tracks_sources() {
+        let simulation = Simulation::run(&example(), None).unwrap();
+        assert_eq!((1..=6).map(|id| simulation.instances(id).unwrap()).collect::<Vec<_>>(), [1, 2, 4, 8, 14, 1]);
+        assert_eq!(simulation.total(), 30);
+        assert_eq!(simulation.won(5), Some(13));
+        assert_eq!(simulation.sources(5), Some(&[(1, 1), (3, 4), (4, 8)][..]));
+        assert_eq!(simulation.sources(1), Some(&[][..]));
+        assert_eq!((simulation.instances(7), simulation.won(7), simulation.sources(7)), (None, None, None));
+    }
+
+    #[test]
+    fn it_keeps_card_ids() {
+        let cards: Vec<Card> = parse::parse_lines("Card 7: 1 2 | 1 2\nCard 12: 1 | 2\nCard 3: 1 | 1").unwrap();
+        let simulation = Simulation::run(&cards, None).unwrap();
+        assert_eq!((simulation.instances(12), simulation.instances(3)), (Some(2), Some(2)));
+        assert_eq!(simulation.sources(3), Some(&[(7, 1)][..]));
+        assert_eq!(Cascade::of(&cards, 7).unwrap().to_string(), "Card 7\n  Card 12\n  Card 3\n");
+    }
+
+    #[test]
+    fn it_rejects_duplicate_ids() {
+        let cards: Vec<Card> = parse::parse_lines("Card 1: 1 | 1\nCard 2: 1 | 2\nCard 1: 3 | 3").unwrap();
+        assert_eq!(Simulation::run(&cards, None).unwrap_err().to_string(), "There are several cards 1");
+        assert_eq!(Cascade::of(&cards, 1), None);
+        assert!(Cascade::of(&cards, 2).is_some());
+    }
+
+    #[test]
+    fn it_caps_copies() {
+        let simulation = Simulation::run(&example(), Some(3)).unwrap();
+        assert_eq!((1..=6).map(|id| simulation.instances(id).unwrap()).collect::<Vec<_>>(), [1, 2, 3, 3, 3, 1]);
+        assert_eq!(simulation.sources(3), Some(&[(1, 1), (2, 1)][..]));
+        assert_eq!(Simulation::run(&example(), Some(1)).unwrap().total(), 6);
+    }
+
+    #[test]
+    fn it_builds_cascades() {
+        let cards = example();
+        let cascade = Cascade::of(&cards, 3).unwrap();
+        assert_eq!(cascade.to_string(), "Card 3\n  Card 4\n    Card 5\n  Card 5\n");
+        assert_eq!(Cascade::of(&cards, 1).unwrap().size(), 15);
+        assert_eq!((1..=6).map(|id| Cascade::of(&cards, id).unwrap().size()).sum::<usize>(), 30);
+        assert_eq!(Cascade::of(&cards, 7), None);
+    }
+}
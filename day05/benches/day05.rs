@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day05::{Day05, INPUT};
+
+fn day05(c: &mut Criterion) {
+    bench::<Day05>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day05>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day05);
+criterion_main!(benches);
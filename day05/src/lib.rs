@@ -0,0 +1,483 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+aoc_common::embedded_input!();
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = (Almanac, Vec<usize>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1((almanac, numbers): &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        almanac.get_minimum(numbers).ok_or_else(|| eyre!("No seeds"))
+    }
+
+    fn part2((almanac, numbers): &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        almanac.get_minimum_from_range(seed_ranges(numbers)?).ok_or_else(|| eyre!("No seed ranges"))
+    }
+}
+
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct MapFunction {
+    destination_start: usize,
+    source_start: usize,
+    len: usize,
+}
+
+impl MapFunction {
+    const fn apply(&self, value: usize) -> Option<usize> {
+        if value < self.source_start || value >= self.source_start + self.len {
+            None
+        } else {
+            Some(self.destination_start + (value - self.source_start))
+        }
+    }
+
+    const fn source(&self) -> Range<usize> {
+        self.source_start..self.source_start + self.len
+    }
+
+    const fn destination(&self) -> Range<usize> {
+        self.destination_start..self.destination_start + self.len
+    }
+
+    /// This function on the part of its source within `range`, if there is one.
+    fn restrict(&self, range: &Range<usize>) -> Option<Self> {
+        let start = range.start.max(self.source_start);
+        let end = range.end.min(self.source().end);
+        (start < end).then(|| Self { destination_start: self.destination_start + (start - self.source_start), source_start: start, len: end - start })
+    }
+
+    /// Maps the part of `range` this function covers, and returns the parts before and after it
+    /// unmapped.
+    fn apply_range(&self, range: &Range<usize>) -> (Option<Range<usize>>, [Range<usize>; 2]) {
+        let source = self.source();
+        let before = range.start..range.end.min(source.start);
+        let after = range.start.max(source.end)..range.end;
+        let covered = range.start.max(source.start)..range.end.min(source.end);
+        let mapped = (!covered.is_empty())
+            .then(|| self.destination_start + (covered.start - source.start)..self.destination_start + (covered.end - source.start));
+        (mapped, [before, after])
+    }
+}
+
+impl FromStr for MapFunction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = parse_numbers(s)?;
+        if let [destination_start, source_start, len] = values[..] {
+            Ok(Self {
+                destination_start,
+                source_start,
+                len,
+            })
+        } else {
+            Err(ParseError::at(s, s, "Expected three numbers"))
+        }
+    }
+}
+
+/// A piecewise linear function on numbers: every function maps a range by an offset, and the
+/// numbers outside of every function map to themselves.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Map {
+    map_functions: Vec<MapFunction>,
+}
+
+impl Map {
+    /// Maps `value`, by the first function covering it.
+    #[must_use]
+    pub fn apply(&self, value: usize) -> usize {
+        self.map_functions.iter().find_map(|f| f.apply(value)).unwrap_or(value)
+    }
+
+    /// Maps every value of `ranges`, splitting them where the functions start and end.
+    fn apply_ranges(&self, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+        let mut mapped = vec![];
+        let mut unmapped = ranges;
+        for function in &self.map_functions {
+            let mut rest = vec![];
+            for range in &unmapped {
+                let (covered, outside) = function.apply_range(range);
+                mapped.extend(covered);
+                rest.extend(outside.into_iter().filter(|range| !range.is_empty()));
+            }
+            unmapped = rest;
+        }
+        // values outside of every function keep their number
+        mapped.extend(unmapped);
+        mapped
+    }
+
+    /// The same function as non-overlapping pieces ordered by source, covering every number below
+    /// `usize::MAX`, with the numbers mapping to themselves as pieces too.
+    fn pieces(&self) -> Vec<MapFunction> {
+        let mut pieces = vec![];
+        let mut unmapped = vec![Range { start: 0, end: usize::MAX }];
+        for function in &self.map_functions {
+            let mut rest = vec![];
+            for range in &unmapped {
+                pieces.extend(function.restrict(range));
+                let source = function.source();
+                rest.extend([range.start..range.end.min(source.start), range.start.max(source.end)..range.end]);
+            }
+            unmapped = rest.into_iter().filter(|range| !range.is_empty()).collect();
+        }
+        pieces.extend(unmapped.into_iter().map(|range| MapFunction { destination_start: range.start, source_start: range.start, len: range.len() }));
+        pieces.sort_unstable_by_key(|piece| piece.source_start);
+        pieces
+    }
+
+    /// A map of ordered `pieces`, joining neighbours that continue each other.
+    fn from_pieces(pieces: impl IntoIterator<Item = MapFunction>) -> Self {
+        let mut map_functions: Vec<MapFunction> = vec![];
+        for piece in pieces {
+            match map_functions.last_mut() {
+                Some(last) if last.source().end == piece.source_start && last.destination().end == piece.destination_start => {
+                    last.len += piece.len;
+                }
+                _ => map_functions.push(piece),
+            }
+        }
+        Self { map_functions }
+    }
+
+    /// This map followed by `next`, as a single map.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let next = next.pieces();
+        let pieces = self.pieces().into_iter().flat_map(|piece| {
+            next.iter().filter_map(move |next| next.restrict(&piece.destination())).map(move |next| MapFunction {
+                destination_start: next.destination_start,
+                source_start: piece.source_start + (next.source_start - piece.destination_start),
+                len: next.len,
+            })
+        });
+        let mut pieces: Vec<MapFunction> = pieces.collect();
+        pieces.sort_unstable_by_key(|piece| piece.source_start);
+        Self::from_pieces(pieces)
+    }
+
+    /// The inverse map, from where numbers end up to every number that ends up there.
+    #[must_use]
+    pub fn invert(&self) -> Inverse {
+        let mut pieces: Vec<MapFunction> = self
+            .pieces()
+            .into_iter()
+            .map(|piece| MapFunction { destination_start: piece.source_start, source_start: piece.destination_start, len: piece.len })
+            .collect();
+        pieces.sort_unstable_by_key(|piece| piece.source_start);
+        Inverse { pieces }
+    }
+
+    /// The smallest number any number of `ranges` maps to, or `None` if they are all empty.
+    #[must_use]
+    pub fn minimum(&self, ranges: &[Range<usize>]) -> Option<usize> {
+        let pieces = self.pieces();
+        ranges.iter().flat_map(|range| pieces.iter().filter_map(|piece| piece.restrict(range))).map(|piece| piece.destination_start).min()
+    }
+}
+
+/// The inverse of a [`Map`], which is not a map itself: a number may be reached from several
+/// numbers, or from none.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Inverse {
+    /// The pieces of the map with their sources and destinations swapped, ordered by source.
+    pieces: Vec<MapFunction>,
+}
+
+impl Inverse {
+    /// Every number mapping to `value`, in order, or none if no number does.
+    #[must_use]
+    pub fn apply(&self, value: usize) -> Vec<usize> {
+        let mut preimages: Vec<usize> = self.pieces.iter().filter_map(|piece| piece.apply(value)).collect();
+        preimages.sort_unstable();
+        preimages
+    }
+}
+
+impl Display for Map {
+    /// One line per piece: `source -> destination`, leaving out the pieces mapping numbers to
+    /// themselves.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for piece in self.pieces().iter().filter(|piece| piece.source_start != piece.destination_start) {
+            writeln!(f, "{:?} -> {:?}", piece.source(), piece.destination())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let header = lines.next().unwrap_or_default();
+        if !header.ends_with("map:") {
+            return Err(ParseError::at(s, header, "Expected a map header"));
+        }
+        let x = lines.map(|line| line.parse::<MapFunction>().map_err(|e| e.within(s, line))).collect::<Result<Vec<MapFunction>, _>>()?;
+        Ok(Self {
+            map_functions: x,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Almanac {
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    fn apply(&self, value: usize) -> usize {
+        self.maps.iter().fold(value, |acc, map| map.apply(acc))
+    }
+
+    fn get_minimum(&self, values: &[usize]) -> Option<usize> {
+        values.iter().map(|v| self.apply(*v)).min()
+    }
+
+    /// All maps as a single map, straight from seed to location.
+    #[must_use]
+    pub fn compose(&self) -> Map {
+        self.maps.iter().fold(Map::default(), |composed, map| composed.then(map))
+    }
+
+    /// Maps every value of `ranges` through all maps, as a few ranges instead of value by value.
+    fn apply_ranges(&self, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+        self.maps.iter().fold(ranges, |ranges, map| map.apply_ranges(ranges))
+    }
+
+    /// The minimum of the values of `ranges`.
+    fn get_minimum_from_range(&self, ranges: Vec<Range<usize>>) -> Option<usize> {
+        let ranges = ranges.into_iter().filter(|range| !range.is_empty()).collect();
+        self.apply_ranges(ranges).into_iter().map(|range| range.start).min()
+    }
+}
+
+/// The ranges described by pairs of seed `values`: a start and a length.
+///
+/// The seeds are parsed as numbers for part 1, so their errors point at the whole first line.
+fn seed_ranges(values: &[usize]) -> Result<Vec<Range<usize>>, ParseError> {
+    let seeds = || format!("seeds: {}", values.iter().map(usize::to_string).collect::<Vec<_>>().join(" "));
+    if !values.len().is_multiple_of(2) {
+        return Err(ParseError::new("Expected pairs of a start and a length", seeds()));
+    }
+    values
+        .chunks_exact(2)
+        .map(|pair| pair[0].checked_add(pair[1]).map(|end| pair[0]..end).ok_or_else(|| ParseError::new(format!("The seeds from {} end after {}", pair[0], usize::MAX), seeds())))
+        .collect()
+}
+
+/// Parses the whitespace separated numbers of `s`.
+fn parse_numbers(s: &str) -> Result<Vec<usize>, ParseError> {
+    s.split_ascii_whitespace().map(|n| n.parse().map_err(|e| ParseError::at(s, n, e))).collect()
+}
+
+fn parse(input: &str) -> Result<(Almanac, Vec<usize>), ParseError> {
+    // removing the carriage returns keeps the lines and columns of everything else
+    let input = input.trim().replace("\r\n", "\n");
+    let mut parts = input.split("\n\n");
+    let seeds = parts.next().unwrap_or_default();
+    let numbers = seeds.strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::at(&input, seeds, "Expected the seeds"))
+        .and_then(|numbers| parse_numbers(numbers).map_err(|e| e.within(&input, numbers)))?;
+    if numbers.is_empty() {
+        return Err(ParseError::at(&input, seeds, "Expected at least one seed"));
+    }
+    let maps: Vec<Map> = parts.map(|section| section.parse().map_err(|e: ParseError| e.within(&input, section))).collect::<Result<Vec<_>, _>>()?;
+    Ok((Almanac { maps }, numbers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE: &str = include_str!("example.txt");
+    #[test]
+    fn it_applies_map_function() {
+        let mf = MapFunction {
+            destination_start: 50,
+            source_start: 98,
+            len: 2,
+        };
+        assert_eq!(mf.apply(97), None);
+        assert_eq!(mf.apply(98), Some(50));
+        assert_eq!(mf.apply(99), Some(51));
+        assert_eq!(mf.apply(100), None);
+    }
+
+    #[test]
+    fn it_applies_map() {
+        let map = Map {
+            map_functions: vec![
+                MapFunction {
+                    destination_start: 50,
+                    source_start: 98,
+                    len: 2,
+                },
+                MapFunction {
+                    destination_start: 52,
+                    source_start: 50,
+                    len: 48,
+                }],
+        };
+        assert_eq!(map.apply(79), 81);
+        assert_eq!(map.apply(14), 14);
+        assert_eq!(map.apply(55), 57);
+        assert_eq!(map.apply(13), 13);
+    }
+
+    #[test]
+    fn it_applies_map_function_to_ranges() {
+        let mf = MapFunction {
+            destination_start: 50,
+            source_start: 98,
+            len: 2,
+        };
+        let (mapped, [before, after]) = mf.apply_range(&(90..95));
+        assert_eq!((mapped, before), (None, 90..95));
+        assert!(after.is_empty());
+        assert_eq!(mf.apply_range(&(97..101)), (Some(50..52), [97..98, 100..101]));
+        let (mapped, [before, after]) = mf.apply_range(&(99..100));
+        assert_eq!(mapped, Some(51..52));
+        assert!(before.is_empty() && after.is_empty());
+    }
+
+    #[test]
+    fn it_applies_almanac_to_ranges_like_values() {
+        let (almanac, _) = parse(EXAMPLE).unwrap();
+        let mut mapped: Vec<usize> = almanac.apply_ranges(vec![0..40, 40..120]).into_iter().flatten().collect();
+        mapped.sort_unstable();
+        let mut expected: Vec<usize> = (0..120).map(|value| almanac.apply(value)).collect();
+        expected.sort_unstable();
+        assert_eq!(mapped, expected);
+    }
+
+    #[test]
+    fn it_parses_function() {
+        let input = "50 98 2";
+        let mf = MapFunction {
+            destination_start: 50,
+            source_start: 98,
+            len: 2,
+        };
+        assert_eq!(MapFunction::from_str(input).unwrap(), mf);
+    }
+
+    #[test]
+    fn it_parses_map() {
+        let input = r"seed-to-soil map:
+50 98 2
+52 50 48";
+        let map = Map {
+            map_functions: vec![
+                MapFunction {
+                    destination_start: 50,
+                    source_start: 98,
+                    len: 2,
+                },
+                MapFunction {
+                    destination_start: 52,
+                    source_start: 50,
+                    len: 48,
+                }],
+        };
+        assert_eq!(Map::from_str(input).unwrap(), map);
+    }
+
+    #[test]
+    fn it_parses_almanac() {
+        let (almanac, numbers) = parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(numbers, vec![79usize, 14, 55, 13]);
+    }
+
+    #[test]
+    fn it_rejects_malformed_almanac() {
+        let err = parse("seeds: 1 2\n\nsoil map:\n1 2 x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 5, "x"));
+        let err = parse("seeds: 1 2\n\nsoil map:\n1 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (4, 1, "1 2"));
+        assert_eq!(parse("1 2").unwrap_err().text, "1 2");
+        assert!(parse("").is_err());
+        let err = parse("seeds:\n\nsoil map:\n1 2 3").unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (1, "Expected at least one seed"));
+    }
+
+    #[test]
+    fn it_applies_almanac() {
+        let (almanac, numbers) = parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.apply(numbers[0]), 82);
+        assert_eq!(almanac.apply(numbers[1]), 43);
+        assert_eq!(almanac.apply(numbers[2]), 86);
+        assert_eq!(almanac.apply(numbers[3]), 35);
+    }
+    #[test]
+    fn it_gets_minimum() {
+        let (almanac, numbers) = parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.get_minimum(&numbers), Some(35));
+    }
+
+    #[test]
+    fn it_gets_minimum_with_ranges() {
+        let (almanac, numbers) = parse(EXAMPLE).unwrap();
+        assert_eq!(almanac.get_minimum_from_range(seed_ranges(&numbers).unwrap()), Some(46));
+        assert_eq!(almanac.get_minimum_from_range(seed_ranges(&[79, 0]).unwrap()), None);
+    }
+
+    #[test]
+    fn it_rejects_malformed_seed_ranges() {
+        assert_eq!(seed_ranges(&[79, 14, 55]).unwrap_err().message, "Expected pairs of a start and a length");
+        let err = seed_ranges(&[79, 14, usize::MAX, 1]).unwrap_err();
+        assert_eq!(err.text, format!("seeds: 79 14 {} 1", usize::MAX));
+        assert_eq!(seed_ranges(&[usize::MAX, 0]).unwrap(), vec![Range { start: usize::MAX, end: usize::MAX }]);
+        let (almanac, numbers) = parse("seeds: 79 14 55\n\nsoil map:\n1 2 3").unwrap();
+        assert!(Day05::part2(&(almanac, numbers)).is_err());
+    }
+
+    #[test]
+    fn it_composes_almanac() {
+        let (almanac, numbers) = parse(EXAMPLE).unwrap();
+        let composed = almanac.compose();
+        assert!((0..120).all(|seed| composed.apply(seed) == almanac.apply(seed)));
+        assert_eq!(composed.minimum(&[79..93, 55..68]), Some(46));
+        assert_eq!(composed.minimum(&[numbers[0]..numbers[0] + 1, 0..0]), Some(82));
+        assert_eq!(composed.minimum(&[]), None);
+    }
+
+    #[test]
+    fn it_inverts_almanac() {
+        let (almanac, _) = parse(EXAMPLE).unwrap();
+        let inverse = almanac.compose().invert();
+        assert_eq!(inverse.apply(46), [82]);
+        assert!((0..120).all(|seed| inverse.apply(almanac.apply(seed)) == [seed]));
+    }
+
+    #[test]
+    fn it_inverts_to_every_preimage() {
+        let map = Map::from_str("seed-to-soil map:\n10 0 5").unwrap();
+        let inverse = map.invert();
+        assert!(inverse.apply(3).is_empty());
+        assert_eq!(inverse.apply(7), [7]);
+        assert_eq!(inverse.apply(12), [2, 12]);
+        assert!((0..20).all(|seed| inverse.apply(map.apply(seed)).contains(&seed)));
+    }
+
+    #[test]
+    fn it_displays_map() {
+        let map = Map::from_str("seed-to-soil map:\n50 98 2\n52 50 48").unwrap();
+        assert_eq!(map.to_string(), "50..98 -> 52..100\n98..100 -> 50..52\n");
+        assert_eq!(Map::default().then(&map), map.then(&Map::default()));
+    }
+}
\ No newline at end of file
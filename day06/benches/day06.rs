@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day06::{Day06, INPUT};
+
+fn day06(c: &mut Criterion) {
+    bench::<Day06>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day06>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day06);
+criterion_main!(benches);
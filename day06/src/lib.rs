@@ -0,0 +1,206 @@
+pub mod physics;
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use aoc_common::{ParseError, Solution};
+use color_eyre::{Result};
+
+pub use crate::physics::{Capped, Curve, Linear, Physics};
+
+aoc_common::embedded_input!();
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    /// The races of part 1, and the single kerned race of part 2.
+    type Input = (Vec<Race>, Race);
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok((parse(input)?, input.parse()?))
+    }
+
+    fn part1((races, _): &Self::Input) -> Result<Self::Answer1> {
+        Ok(races.iter().map(Race::winning_options).product())
+    }
+
+    fn part2((_, race): &Self::Input) -> Result<Self::Answer2> {
+        Ok(race.winning_options())
+    }
+}
+
+/// A boat race: its time in ms and the record distance in mm to beat.
+///
+/// Distances too long for a `u128` beat every record, so the time can be anything up to
+/// `u128::MAX`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Race {
+    time: u128,
+    record_distance: u128,
+}
+
+impl FromStr for Race {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (times, distances) = split_lines(s)?;
+        Ok(Self {time: kerned(s, times)?, record_distance: kerned(s, distances)?})
+    }
+}
+
+impl Race {
+    /// The button durations beating the record with the puzzle's [`Linear`] physics, or `None`
+    /// if none does.
+    #[must_use]
+    pub fn winning_interval(&self) -> Option<RangeInclusive<u128>> {
+        self.winning_interval_with(&Linear::default())
+    }
+
+    /// The charges beating the record with `physics`, or `None` if none does.
+    pub fn winning_interval_with(&self, physics: &impl Physics) -> Option<RangeInclusive<u128>> {
+        physics.winning_interval(self.time, self.record_distance)
+    }
+
+    /// The shortest charge beating the record with `physics`, or `None` if none does.
+    pub fn min_charge(&self, physics: &impl Physics) -> Option<u128> {
+        self.winning_interval_with(physics).map(|interval| *interval.start())
+    }
+
+    /// The shortest charge travelling the furthest with `physics`.
+    pub fn optimal_charge(&self, physics: &impl Physics) -> u128 {
+        physics.optimal_charge(self.time)
+    }
+
+    /// The number of button durations beating the record.
+    #[must_use]
+    pub fn winning_options(&self) -> u128 {
+        self.winning_interval().map_or(0, |interval| interval.end() - interval.start() + 1)
+    }
+}
+
+/// The values of the `Time:` and `Distance:` lines.
+fn split_lines(input: &str) -> std::result::Result<(&str, &str), ParseError> {
+    let mut lines = input.trim().lines();
+    let mut values = |label: &str| {
+        let line = lines.next().unwrap_or_default();
+        line.strip_prefix(label).ok_or_else(|| ParseError::at(input, line, format!("Expected `{label}`")))
+    };
+    let times = values("Time:")?;
+    let distances = values("Distance:")?;
+    Ok((times, distances))
+}
+
+fn parse(input: &str) -> std::result::Result<Vec<Race>, ParseError> {
+    let (times, distances) = split_lines(input)?;
+    let (times, record_distances) = (numbers::<u128>(input, times)?, numbers::<u128>(input, distances)?);
+    if times.len() != record_distances.len() {
+        return Err(ParseError::at(input, distances, "Expected a distance for every time"));
+    }
+    Ok(times.into_iter().zip(record_distances).map(|(time, record_distance)| Race { time, record_distance }).collect())
+}
+
+/// The number of `values`, a slice of `input`, with the spaces between its digits removed.
+fn kerned<T: FromStr<Err: Display>>(input: &str, values: &str) -> std::result::Result<T, ParseError> {
+    values.replace(' ', "").parse().map_err(|e| ParseError::at(input, values, e))
+}
+
+/// The whitespace separated numbers of `values`, a slice of `input`.
+fn numbers<T: FromStr<Err: Display>>(input: &str, values: &str) -> std::result::Result<Vec<T>, ParseError> {
+    values.split_ascii_whitespace().map(|v| v.parse().map_err(|e| ParseError::at(input, v, e))).collect()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+    #[test]
+    fn it_parses_input() {
+        let races = parse(EXAMPLE).unwrap();
+        assert_eq!(races, vec![Race{time: 7, record_distance: 9}, Race{time: 15, record_distance: 40}, Race{time: 30, record_distance: 200}]);
+    }
+    #[test]
+    fn it_rejects_malformed_input() {
+        let err = parse("Time: 1 2\nDistance: 3").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 10, " 3"));
+        let err = "Time: 1 2\nDistance: 3 x".parse::<Race>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 10));
+        assert!(parse("Time: 1").is_err());
+    }
+
+    #[test]
+    fn it_calculates_wins() {
+        let races = parse(EXAMPLE).unwrap();
+        assert_eq!(races[0].winning_options(), 4);
+        assert_eq!(races[1].winning_options(), 8);
+        assert_eq!(races[2].winning_options(), 9);
+        assert_eq!(races.iter().map(Race::winning_interval).collect::<Vec<_>>(), [Some(2..=5), Some(4..=11), Some(11..=19)]);
+    }
+
+    #[test]
+    fn it_matches_counting_every_duration() {
+        for time in 0..40 {
+            for record_distance in 0..(time * time / 4 + 2) {
+                let race = Race { time, record_distance };
+                let wins: Vec<u128> = (0..=time).filter(|&d| d * (time - d) > record_distance).collect();
+                assert_eq!(race.winning_interval(), wins.first().zip(wins.last()).map(|(&low, &high)| low..=high), "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn it_matches_counting_durations_beyond_u64() {
+        let beyond = u128::from(u64::MAX) + 1;
+        for time in [beyond, beyond + 1, beyond * 3 + 7, 1 << 100, u128::MAX] {
+            let wins = |record_distance: u128, d: u128| d.checked_mul(time - d).is_none_or(|distance| distance > record_distance);
+            // records beaten at short durations, and at durations close to half the time
+            let short = (1..40).map(|d: u128| d.saturating_mul(time - d));
+            let half = time / 2;
+            let long = (half - 40..=half).filter_map(|d| d.checked_mul(time - d));
+            for record_distance in short.chain(long).flat_map(|distance| [distance - 1, distance, distance.saturating_add(1)]) {
+                // the durations are too many to count, but the ends are close to the edges or the middle
+                let low = (0..=41).chain(half - 41..=half).find(|&d| wins(record_distance, d));
+                let high = (half..=half + 41).chain(time - 41..=time).rev().find(|&d| wins(record_distance, d));
+                let race = Race { time, record_distance };
+                assert_eq!(race.winning_interval(), low.zip(high).map(|(low, high)| low..=high), "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn it_solves_huge_races() {
+        let time = u128::from(u64::MAX);
+        let race = Race { time, record_distance: 0 };
+        assert_eq!(race.winning_interval(), Some(1..=time - 1));
+        let best = time / 2 * (time - time / 2);
+        assert_eq!(Race { time, record_distance: best - 1 }.winning_options(), 2);
+        assert_eq!(Race { time, record_distance: best }.winning_interval(), None);
+        assert_eq!(Race { time: 3, record_distance: u128::MAX }.winning_options(), 0);
+        assert_eq!(Race { time: u128::MAX, record_distance: u128::MAX }.winning_interval(), Some(2..=u128::MAX - 2));
+    }
+
+    #[test]
+    fn it_finds_charges() {
+        let races = parse(EXAMPLE).unwrap();
+        assert_eq!(races.iter().map(|race| race.min_charge(&Linear::default())).collect::<Vec<_>>(), [Some(2), Some(4), Some(11)]);
+        assert_eq!(races.iter().map(|race| race.optimal_charge(&Linear::default())).collect::<Vec<_>>(), [3, 7, 15]);
+        let capped = Capped { rate: 1, max_speed: 12 };
+        assert_eq!((races[2].min_charge(&capped), races[2].optimal_charge(&capped)), (Some(11), 12));
+        assert_eq!(races[2].winning_interval_with(&Linear { rate: 2 }), Some(4..=26));
+    }
+
+    #[test]
+    fn it_parses_part2() {
+        let race: Race = EXAMPLE.parse().unwrap();
+        assert_eq!(race, Race{time: 71_530, record_distance: 940_200});
+    }
+    #[test]
+    fn it_solves_example_part2() {
+        let race: Race = EXAMPLE.parse().unwrap();
+        assert_eq!(race.winning_options(), 71_503);
+        assert_eq!(race.winning_interval(), Some(14..=71_516));
+    }
+
+}
\ No newline at end of file
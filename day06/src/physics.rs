@@ -0,0 +1,217 @@
+use std::ops::RangeInclusive;
+
+/// How fast a boat goes after charging, and so how far it travels in a race.
+pub trait Physics {
+    /// The speed in mm/ms after holding the button for `charge` ms.
+    fn speed(&self, charge: u128) -> u128;
+
+    /// The distance in mm travelled in a race of `time` ms after charging for `charge` ms of it,
+    /// or `u128::MAX` if it is even further.
+    fn distance(&self, charge: u128, time: u128) -> u128 {
+        self.speed(charge).saturating_mul(time.saturating_sub(charge))
+    }
+
+    /// The charges travelling further than `record` in a race of `time` ms, or `None` if none
+    /// does.
+    ///
+    /// The charges are assumed to win in a single interval, as they do when the distance rises
+    /// to its maximum and falls after it. By default, every charge is tried, which takes too long
+    /// for long races: physics racing them should solve it directly.
+    fn winning_interval(&self, time: u128, record: u128) -> Option<RangeInclusive<u128>> {
+        let wins = |charge| self.distance(charge, time) > record;
+        let low = (0..=time).find(|&charge| wins(charge))?;
+        let high = (low..=time).rev().find(|&charge| wins(charge))?;
+        Some(low..=high)
+    }
+
+    /// The smallest charge travelling the furthest in a race of `time` ms.
+    ///
+    /// By default, every charge is tried.
+    fn optimal_charge(&self, time: u128) -> u128 {
+        // of equal distances, the last one is the maximum, and the smallest charge when reversed
+        (0..=time).rev().max_by_key(|&charge| self.distance(charge, time)).unwrap_or_default()
+    }
+}
+
+/// Gaining `rate` mm/ms of speed per ms of charging, without friction: the puzzle's physics with
+/// the default rate of 1.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Linear {
+    pub rate: u64,
+}
+
+impl Default for Linear {
+    fn default() -> Self {
+        Self { rate: 1 }
+    }
+}
+
+impl Physics for Linear {
+    fn speed(&self, charge: u128) -> u128 {
+        u128::from(self.rate).saturating_mul(charge)
+    }
+
+    fn winning_interval(&self, time: u128, record: u128) -> Option<RangeInclusive<u128>> {
+        // rate * d * (time - d) > record exactly when d * (time - d) > record / rate, rounded down
+        (self.rate > 0).then(|| quadratic_interval(time, record / u128::from(self.rate)))?
+    }
+
+    fn optimal_charge(&self, time: u128) -> u128 {
+        if self.rate > 0 {
+            time / 2
+        } else {
+            0
+        }
+    }
+}
+
+/// [`Linear`] charging up to a maximum speed, after which charging only wastes time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Capped {
+    pub rate: u64,
+    pub max_speed: u128,
+}
+
+impl Physics for Capped {
+    fn speed(&self, charge: u128) -> u128 {
+        u128::from(self.rate).saturating_mul(charge).min(self.max_speed)
+    }
+
+    fn winning_interval(&self, time: u128, record: u128) -> Option<RangeInclusive<u128>> {
+        let wins = |charge| self.distance(charge, time) > record;
+        let peak = self.optimal_charge(time);
+        if !wins(peak) {
+            return None;
+        }
+        // the distance rises up to the peak and falls after it, down to nothing at the full time
+        Some(first(0, peak, wins)..=first(peak, time, |charge| !wins(charge)) - 1)
+    }
+
+    fn optimal_charge(&self, time: u128) -> u128 {
+        if self.rate == 0 || self.max_speed == 0 {
+            return 0;
+        }
+        // the speed stops growing from this charge on, after which the distance only falls
+        let capped = self.max_speed.div_ceil(u128::from(self.rate));
+        if time / 2 < capped {
+            // the distance peaks as without a cap, as the capped charges go even less far
+            time / 2
+        } else if self.distance(capped - 1, time) >= self.distance(capped, time) {
+            capped - 1
+        } else {
+            capped
+        }
+    }
+}
+
+/// Any charge curve, as a function from the charge in ms to the speed in mm/ms.
+#[derive(Copy, Clone, Debug)]
+pub struct Curve<F>(pub F);
+
+impl<F: Fn(u128) -> u128> Physics for Curve<F> {
+    fn speed(&self, charge: u128) -> u128 {
+        (self.0)(charge)
+    }
+}
+
+/// The durations `d` with `d * (time - d) > record`, or `None` if there are none.
+///
+/// The winning durations lie strictly between the roots of `d² - time * d + record`. Those are
+/// estimated with an integer square root of the discriminant and corrected by a step or two,
+/// without any rounding. For times of 2⁶⁴ ms and more, the discriminant does not fit in a `u128`
+/// and the shortest winning duration is bisected for instead.
+fn quadratic_interval(time: u128, record: u128) -> Option<RangeInclusive<u128>> {
+    // a distance too long for a u128 beats any record
+    let wins = |duration: u128| duration.checked_mul(time - duration).is_none_or(|distance| distance > record);
+    // the best duration is half the time
+    let half = time / 2;
+    if !wins(half) {
+        return None;
+    }
+    // as half the time wins, 4 * record < half * (time - half) * 4 <= time * time
+    let mut low = time.checked_mul(time).map_or_else(|| first(0, half, wins), |square| (time - (square - record * 4).isqrt()) / 2);
+    while !wins(low) {
+        low += 1;
+    }
+    while low > 0 && wins(low - 1) {
+        low -= 1;
+    }
+    // the distance is symmetric around half the time
+    Some(low..=time - low)
+}
+
+/// The first value from `low` to `high` that `holds`, bisecting on it being false up to some value
+/// and true from there on to `high`.
+fn first(mut low: u128, mut high: u128, holds: impl Fn(u128) -> bool) -> u128 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if holds(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_solves_linear_physics_like_trying_every_charge() {
+        for rate in 0..4 {
+            let linear = Linear { rate };
+            let curve = Curve(|charge| u128::from(rate) * charge);
+            for time in 0..30 {
+                for record in 0..(u128::from(rate) * time * time / 4 + 2) {
+                    assert_eq!(linear.winning_interval(time, record), curve.winning_interval(time, record), "{rate} {time} {record}");
+                }
+                assert_eq!(linear.distance(linear.optimal_charge(time), time), curve.distance(curve.optimal_charge(time), time));
+            }
+        }
+    }
+
+    #[test]
+    fn it_solves_capped_physics_like_trying_every_charge() {
+        for rate in 0..4 {
+            for max_speed in 0..12 {
+                let capped = Capped { rate, max_speed };
+                let curve = Curve(|charge: u128| (u128::from(rate) * charge).min(max_speed));
+                for time in 0..25 {
+                    for record in 0..(max_speed * time + 2) {
+                        assert_eq!(capped.winning_interval(time, record), curve.winning_interval(time, record), "{capped:?} {time} {record}");
+                    }
+                    assert_eq!(capped.optimal_charge(time), curve.optimal_charge(time), "{capped:?} {time}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_caps_speed_in_huge_races() {
+        let capped = Capped { rate: 1, max_speed: 10 };
+        let time = 1 << 100;
+        assert_eq!(capped.optimal_charge(time), 10);
+        assert_eq!(capped.winning_interval(time, 0), Some(1..=time - 1));
+        assert_eq!(capped.winning_interval(time, capped.distance(10, time) - 1), Some(10..=10));
+        assert_eq!(capped.winning_interval(time, capped.distance(10, time)), None);
+    }
+
+    #[test]
+    fn it_caps_speed() {
+        let capped = Capped { rate: 1, max_speed: 12 };
+        assert_eq!(capped.distance(20, 30), 120);
+        assert_eq!(capped.winning_interval(30, 200), Some(11..=13));
+        assert_eq!(capped.optimal_charge(30), 12);
+        assert_eq!(Linear::default().winning_interval(30, 200), Some(11..=19));
+    }
+
+    #[test]
+    fn it_follows_curves() {
+        let quadratic = Curve(|charge: u128| charge * charge);
+        assert_eq!(quadratic.winning_interval(7, 9), Some(2..=6));
+        assert_eq!(quadratic.optimal_charge(7), 5);
+        assert_eq!(quadratic.winning_interval(7, 50), None);
+    }
+}
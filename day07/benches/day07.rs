@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day07::{Day07, INPUT};
+
+fn day07(c: &mut Criterion) {
+    bench::<Day07>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day07>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day07);
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+use std::fmt::{self, Display, Formatter};
+use std::rc::Rc;
+
+use crate::{Card, Hand, HandType, Ruleset};
+
+/// What a hand amounts to under a ruleset, wildcards and all.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Analysis {
+    /// The cards as dealt.
+    pub cards: Vec<Card>,
+    /// The cards with the wildcards replaced by the cards they stand in for.
+    pub substituted: Vec<Card>,
+    /// The type of the cards at face value, without wildcards.
+    pub natural_type: HandType,
+    /// The best type, with the wildcards standing in for other cards.
+    pub hand_type: HandType,
+}
+
+/// Why one hand beats another.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Explanation {
+    /// The stronger hand, or the first one of a tie.
+    pub winner: Analysis,
+    /// The weaker hand, or the second one of a tie.
+    pub loser: Analysis,
+    pub decided_by: Decider,
+}
+
+/// What decides between two hands.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Decider {
+    /// The hands have different types.
+    HandType,
+    /// The hands have the same type, and the card at this position, starting at 0, is the first
+    /// one with a different strength.
+    Card(usize),
+    /// The hands have the same type and cards of the same strengths.
+    Tie,
+}
+
+impl Hand {
+    /// What the hand amounts to under the ruleset it was evaluated with.
+    #[must_use]
+    pub fn analyse(&self) -> Analysis {
+        Analysis {
+            cards: self.cards.clone(),
+            substituted: self.ruleset.substitute(&self.cards),
+            natural_type: Ruleset::clone(&self.ruleset).with_wildcards(&[]).hand_type(&self.cards),
+            hand_type: self.hand_type.clone(),
+        }
+    }
+
+    /// Why this hand beats `other`, loses to it or ties with it, or `None` if they were evaluated
+    /// with different rulesets and so cannot be compared.
+    #[must_use]
+    pub fn explain(&self, other: &Self) -> Option<Explanation> {
+        if !Rc::ptr_eq(&self.ruleset, &other.ruleset) && self.ruleset != other.ruleset {
+            return None;
+        }
+        let (winner, loser) = if self >= other { (self, other) } else { (other, self) };
+        let decided_by = if winner.sort_order.hand_type == loser.sort_order.hand_type {
+            winner.sort_order.cards.iter().zip(&loser.sort_order.cards).position(|(a, b)| a != b).map_or(Decider::Tie, Decider::Card)
+        } else {
+            Decider::HandType
+        };
+        Some(Explanation { winner: winner.analyse(), loser: loser.analyse(), decided_by })
+    }
+}
+
+impl Display for Analysis {
+    /// The cards, with their substitution if it differs, and the type.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_cards(f, &self.cards)?;
+        write!(f, " ({}", self.hand_type)?;
+        if self.substituted != self.cards {
+            write!(f, " as ")?;
+            write_cards(f, &self.substituted)?;
+            write!(f, ", {} at face value", self.natural_type)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (winner, loser) = (&self.winner, &self.loser);
+        match self.decided_by {
+            Decider::HandType => write!(f, "{winner} beats {loser}: {} beats {}", winner.hand_type, loser.hand_type),
+            Decider::Card(position) => {
+                write!(f, "{winner} beats {loser}: both are {}, ", winner.hand_type)?;
+                if position > 0 {
+                    write!(f, "the cards before card {} tie, ", position + 1)?;
+                }
+                write!(f, "card {} decides with {} beating {}", position + 1, winner.cards[position], loser.cards[position])
+            }
+            Decider::Tie => write!(f, "{winner} ties with {loser}: both are {} with cards of equal strength", winner.hand_type),
+        }
+    }
+}
+
+fn write_cards(f: &mut Formatter<'_>, cards: &[Card]) -> fmt::Result {
+    cards.iter().try_for_each(|card| write!(f, "{card}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(s: &str, ruleset: &Rc<Ruleset>) -> Hand {
+        Hand::parse_with(&format!("{s} 0"), ruleset).unwrap()
+    }
+
+    #[test]
+    fn it_analyses_jokers() {
+        let jokers = Rc::new(Ruleset::jokers());
+        let analysis = hand("KTJJT", &jokers).analyse();
+        assert_eq!(analysis.substituted, hand("KTTTT", &jokers).cards);
+        assert_eq!((analysis.natural_type.to_string(), analysis.hand_type.to_string()), ("two pair".to_owned(), "four of a kind".to_owned()));
+        assert_eq!(analysis.to_string(), "KTJJT (four of a kind as KTTTT, two pair at face value)");
+        assert_eq!(hand("32T3K", &jokers).analyse().to_string(), "32T3K (one pair)");
+        let standard = hand("KTJJT", &Rc::new(Ruleset::standard()));
+        assert_eq!(standard.analyse().to_string(), "KTJJT (two pair)");
+        assert_eq!(standard.with_ruleset(&jokers).unwrap().analyse(), analysis);
+    }
+
+    #[test]
+    fn it_explains_hand_types() {
+        let standard = Rc::new(Ruleset::standard());
+        let explanation = hand("32T3K", &standard).explain(&hand("T55J5", &standard)).unwrap();
+        assert_eq!(explanation.decided_by, Decider::HandType);
+        assert_eq!(explanation.to_string(), "T55J5 (three of a kind) beats 32T3K (one pair): three of a kind beats one pair");
+    }
+
+    #[test]
+    fn it_explains_ties_by_card() {
+        let standard = Rc::new(Ruleset::standard());
+        let explanation = hand("KTJJT", &standard).explain(&hand("KK677", &standard)).unwrap();
+        assert_eq!(explanation.decided_by, Decider::Card(1));
+        assert_eq!(
+            explanation.to_string(),
+            "KK677 (two pair) beats KTJJT (two pair): both are two pair, the cards before card 2 tie, card 2 decides with K beating T"
+        );
+        let jokers = Rc::new(Ruleset::jokers());
+        let explanation = hand("JKKK2", &jokers).explain(&hand("QQQQ2", &jokers)).unwrap();
+        assert_eq!(explanation.decided_by, Decider::Card(0));
+        assert!(explanation.to_string().ends_with("both are four of a kind, card 1 decides with Q beating J"));
+    }
+
+    #[test]
+    fn it_explains_ties() {
+        let jokers = Rc::new(Ruleset::jokers());
+        let explanation = hand("QQQJA", &jokers).explain(&hand("QQQJA", &jokers)).unwrap();
+        assert_eq!(explanation.decided_by, Decider::Tie);
+        assert!(explanation.to_string().contains(" ties with "));
+    }
+
+    #[test]
+    fn it_explains_only_hands_of_equal_rulesets() {
+        let (standard, jokers) = (Rc::new(Ruleset::standard()), Rc::new(Ruleset::jokers()));
+        assert_eq!(hand("KTJJT", &standard).explain(&hand("KK677", &jokers)), None);
+        let other = Rc::new(Ruleset::standard());
+        assert!(hand("KTJJT", &standard).explain(&hand("KK677", &other)).is_some());
+    }
+}
@@ -0,0 +1,302 @@
+pub mod analysis;
+pub mod ruleset;
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+use std::str::FromStr;
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+use Card::{Ace, Eight, Five, Four, Jack, King, Nine, Queen, Seven, Six, Ten, Three, Two};
+
+pub use crate::analysis::{Analysis, Decider, Explanation};
+pub use crate::ruleset::{HandType, Ruleset, SortOrder};
+
+aoc_common::embedded_input!();
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Hand>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let standard = Rc::new(Ruleset::standard());
+        let hands = input.trim().lines().map(|line| Hand::parse_with(line, &standard).map_err(|e| e.within(input, line)));
+        Ok(hands.collect::<std::result::Result<_, _>>()?)
+    }
+
+    fn part1(hands: &Self::Input) -> Result<Self::Answer1> {
+        Ok(get_total_winnings(hands))
+    }
+
+    fn part2(hands: &Self::Input) -> Result<Self::Answer2> {
+        let jokers = Rc::new(Ruleset::jokers());
+        let hands: Vec<Hand> = hands.iter().map(|hand| hand.with_ruleset(&jokers)).collect::<Result<_>>()?;
+        Ok(get_total_winnings(&hands))
+    }
+}
+
+/// A card, by its face: how strong it is depends on the [`Ruleset`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u8)]
+pub enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Ace => "A",
+            King => "K",
+            Queen => "Q",
+            Jack => "J",
+            Ten => "T",
+            Nine => "9",
+            Eight => "8",
+            Seven => "7",
+            Six => "6",
+            Five => "5",
+            Four => "4",
+            Three => "3",
+            Two => "2",
+        })
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            'A' => Ok(Ace),
+            'K' => Ok(King),
+            'Q' => Ok(Queen),
+            'J' => Ok(Jack),
+            'T' => Ok(Ten),
+            '9' => Ok(Nine),
+            '8' => Ok(Eight),
+            '7' => Ok(Seven),
+            '6' => Ok(Six),
+            '5' => Ok(Five),
+            '4' => Ok(Four),
+            '3' => Ok(Three),
+            '2' => Ok(Two),
+            v => Err(ParseError::new("Not a card", v)),
+        }
+    }
+}
+
+/// A hand and its bid, evaluated by a [`Ruleset`].
+///
+/// Hands are ordered, and equal, by their strength alone: the same cards with different bids are
+/// equal.
+#[derive(Clone, Debug)]
+pub struct Hand {
+    cards: Vec<Card>,
+    #[allow(clippy::struct_field_names)]
+    hand_type: HandType,
+    sort_order: SortOrder,
+    bid: usize,
+    /// The rules the hand was evaluated with, shared by all hands evaluated with them.
+    ruleset: Rc<Ruleset>,
+}
+
+impl Hand {
+    /// Evaluates `cards` with `ruleset`, which has to play all of them.
+    fn new(cards: Vec<Card>, bid: usize, ruleset: &Rc<Ruleset>) -> Self {
+        let hand_type = ruleset.hand_type(&cards);
+        let sort_order = ruleset.sort_order(&cards, &hand_type);
+        Self { cards, hand_type, sort_order, bid, ruleset: Rc::clone(ruleset) }
+    }
+
+    /// Parses a hand of the cards and the bid, separated by a space, evaluated with `ruleset`.
+    ///
+    /// # Errors
+    /// If a card is unknown or not played by `ruleset`, the hand has the wrong size or the bid is
+    /// not a number.
+    pub fn parse_with(s: &str, ruleset: &Rc<Ruleset>) -> std::result::Result<Self, ParseError> {
+        let (cards, bid) = s.trim().split_once(' ').ok_or_else(|| ParseError::at(s, s, "Expected cards and a bid"))?;
+        let parsed = cards
+            .char_indices()
+            .map(|(i, c)| Card::try_from(c).map_err(|e| e.within(s, &cards[i..])))
+            .collect::<std::result::Result<Vec<Card>, _>>()?;
+        ruleset.check(&parsed).map_err(|message| ParseError::at(s, cards, message))?;
+        Ok(Self::new(parsed, bid.parse().map_err(|e| ParseError::at(s, bid, e))?, ruleset))
+    }
+
+    /// The same hand, evaluated with `ruleset` instead.
+    ///
+    /// # Errors
+    /// If `ruleset` does not play the hand.
+    pub fn with_ruleset(&self, ruleset: &Rc<Ruleset>) -> Result<Self> {
+        ruleset.check(&self.cards).map_err(|message| eyre!("{self}: {message}"))?;
+        Ok(Self::new(self.cards.clone(), self.bid, ruleset))
+    }
+
+    #[must_use]
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    #[must_use]
+    pub const fn hand_type(&self) -> &HandType {
+        &self.hand_type
+    }
+
+    #[must_use]
+    pub const fn bid(&self) -> usize {
+        self.bid
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.sort_order == other.sort_order
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_order.cmp(&other.sort_order)
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    /// Parses a hand evaluated with the [`Ruleset::standard`] rules.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::parse_with(s, &Rc::new(Ruleset::standard()))
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for c in &self.cards {
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+fn get_total_winnings(hands: &[Hand]) -> usize {
+    let mut hands: Vec<Hand> = hands.to_vec();
+    hands.sort_unstable();
+    hands.iter().enumerate().map(|(index, hand)| (index + 1) * hand.bid).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn it_compares_hands() {
+        let standard = Rc::new(Ruleset::standard());
+        let a = Hand::new(vec![Three, Three, Three, Three, Two], 0, &standard);
+        let b = Hand::new(vec![Two, Ace, Ace, Ace, Ace], 0, &standard);
+        assert!(a > b);
+        let a = Hand::new(vec![Seven, Seven, Eight, Eight, Eight], 0, &standard);
+        let b = Hand::new(vec![Seven, Seven, Seven, Eight, Eight], 0, &standard);
+        assert!(a > b);
+    }
+
+    #[test]
+    fn it_equates_hands_by_strength() {
+        let a: Hand = "32T3K 765".parse().unwrap();
+        let b: Hand = "32T3K 1".parse().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_ne!(a, "32T3Q 765".parse().unwrap());
+    }
+
+    #[test]
+    fn it_shares_rulesets() {
+        let jokers = Rc::new(Ruleset::jokers());
+        let hands = Day07::parse(EXAMPLE).unwrap();
+        assert!(hands.windows(2).all(|pair| Rc::ptr_eq(&pair[0].ruleset, &pair[1].ruleset)));
+        let hands: Vec<Hand> = hands.iter().map(|hand| hand.with_ruleset(&jokers).unwrap()).collect();
+        assert!(hands.iter().all(|hand| Rc::ptr_eq(&hand.ruleset, &jokers)));
+    }
+
+    #[test]
+    fn it_parses_hand() {
+        let input = "32T3K 765";
+        let hand: Hand = input.parse().unwrap();
+        assert_eq!(hand.bid, 765);
+        assert_eq!(hand.hand_type, HandType::Groups(vec![2, 1, 1, 1]));
+    }
+
+    #[test]
+    fn it_rejects_malformed_hand() {
+        let err = "32X3K 765".parse::<Hand>().unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (3, "X"));
+        assert_eq!("32T3 765".parse::<Hand>().unwrap_err().message, "Expected 5 cards");
+        let err = Hand::parse_with("32T3K 765", &Rc::new(Ruleset::new(&[Two, Three, King]))).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (1, "T is not played"));
+        assert!("32T3K 765".parse::<Hand>().unwrap().with_ruleset(&Rc::new(Ruleset::standard().with_hand_size(4))).is_err());
+        assert_eq!("32T3K x".parse::<Hand>().unwrap_err().column, 7);
+    }
+
+    #[test]
+    fn it_gets_total_winnings() -> Result<()> {
+        let hands: Vec<Hand> = EXAMPLE.trim().lines().map(Hand::from_str).collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(get_total_winnings(&hands), 6440);
+        Ok(())
+    }
+
+    #[test]
+    fn it_gets_total_winnings_with_joker() -> Result<()> {
+        let jokers = Rc::new(Ruleset::jokers());
+        let hands: Vec<Hand> = EXAMPLE.trim().lines().map(|l| Hand::parse_with(l, &jokers)).collect::<std::result::Result<Vec<_>, _>>()?;
+        assert_eq!(get_total_winnings(&hands), 5905);
+        Ok(())
+    }
+
+    #[test]
+    fn it_gets_ranking_with_joker() -> Result<()> {
+        let jokers = Rc::new(Ruleset::jokers());
+        let mut hands: Vec<Hand> = EXAMPLE.trim().lines().map(|l| Hand::parse_with(l, &jokers)).collect::<std::result::Result<Vec<_>, _>>()?;
+        hands.sort_unstable();
+        assert_eq!(hands[0].to_string(), "32T3K");
+        assert_eq!(hands[1].to_string(), "KK677");
+        assert_eq!(hands[2].to_string(), "T55J5");
+        assert_eq!(hands[3].to_string(), "QQQJA");
+        assert_eq!(hands[4].to_string(), "KTJJT");
+        Ok(())
+    }
+
+    #[test]
+    fn it_ranks_joker_correctly() {
+        let jokers = Rc::new(Ruleset::jokers());
+        let a = Hand::parse_with("J2223 0", &jokers).unwrap();
+        let b: Hand = "2KKKK 0".parse().unwrap();
+        let b = b.with_ruleset(&jokers).unwrap();
+        assert!(b > a);
+    }
+}
\ No newline at end of file
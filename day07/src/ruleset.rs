@@ -0,0 +1,324 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+
+use crate::Card;
+use crate::Card::{Ace, Eight, Five, Four, Jack, King, Nine, Queen, Seven, Six, Ten, Three, Two};
+
+/// The type of a hand, before comparing its cards one by one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HandType {
+    /// The sizes of the groups of equal cards, largest first, like `[3, 2]` for a full house.
+    Groups(Vec<usize>),
+    /// Cards of consecutive strengths, if the ruleset has straights.
+    Straight,
+}
+
+impl Display for HandType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Self::Groups(groups) = self else {
+            return write!(f, "straight");
+        };
+        let larger: Vec<usize> = groups.iter().copied().filter(|&size| size > 1).collect();
+        match larger.as_slice() {
+            [] => write!(f, "high card"),
+            [2] => write!(f, "one pair"),
+            [2, 2] => write!(f, "two pair"),
+            [3, 2] => write!(f, "full house"),
+            [3] => write!(f, "three of a kind"),
+            [4] => write!(f, "four of a kind"),
+            [5] => write!(f, "five of a kind"),
+            [size] => write!(f, "{size} of a kind"),
+            sizes => write!(f, "groups of {}", sizes.iter().map(usize::to_string).collect::<Vec<_>>().join(", ")),
+        }
+    }
+}
+
+/// What hands are sorted by: their type, then the strengths of their cards from first to last.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SortOrder {
+    /// The groups of the type, and whether it is a straight ranked right above them.
+    pub hand_type: (Vec<usize>, bool),
+    /// The strength of every card, in the order of the hand.
+    pub cards: Vec<u8>,
+}
+
+/// The rules of a game of Camel Cards: how many cards a hand has, how strong every card is,
+/// which cards are wild and which types of hands there are.
+///
+/// Hand types made of groups of equal cards are ranked by their largest group, then by their
+/// second largest and so on, so for five cards five of a kind beats four of a kind, which beats a
+/// full house, which beats three of a kind, two pair, one pair and high card in that order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ruleset {
+    /// The cards from weakest to strongest.
+    order: Vec<Card>,
+    /// The cards standing in for whichever card makes the best hand type.
+    wildcards: Vec<Card>,
+    hand_size: usize,
+    /// The groups a straight beats, along with every weaker hand type, if there are straights.
+    straight_beats: Option<Vec<usize>>,
+}
+
+impl Ruleset {
+    /// The rules of part 1: five cards from `2` to `A` and no wildcards.
+    #[must_use]
+    pub fn standard() -> Self {
+        Self::new(&[Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Jack, Queen, King, Ace])
+    }
+
+    /// The rules of part 2: `J` is a joker, wild but the weakest card on its own.
+    #[must_use]
+    pub fn jokers() -> Self {
+        Self::new(&[Jack, Two, Three, Four, Five, Six, Seven, Eight, Nine, Ten, Queen, King, Ace]).with_wildcards(&[Jack])
+    }
+
+    /// Hands of five cards of `order`, from weakest to strongest, without wildcards or straights.
+    ///
+    /// # Panics
+    /// If a card is in `order` twice, or it has more than 256 cards.
+    #[must_use]
+    pub fn new(order: &[Card]) -> Self {
+        assert!(order.len() <= 256, "The order has more than 256 cards");
+        for (i, card) in order.iter().enumerate() {
+            assert!(!order[..i].contains(card), "{card} is in the order twice");
+        }
+        Self { order: order.to_vec(), wildcards: vec![], hand_size: 5, straight_beats: None }
+    }
+
+    /// The same rules, with `wildcards` standing in for whichever card makes the best hand type.
+    #[must_use]
+    pub fn with_wildcards(mut self, wildcards: &[Card]) -> Self {
+        self.wildcards = wildcards.to_vec();
+        self
+    }
+
+    /// The same rules for hands of `hand_size` cards.
+    ///
+    /// # Panics
+    /// If `hand_size` is 0.
+    #[must_use]
+    pub fn with_hand_size(mut self, hand_size: usize) -> Self {
+        assert!(hand_size > 0, "A hand needs cards");
+        self.hand_size = hand_size;
+        self
+    }
+
+    /// The same rules with straights, ranked above the type of groups `beats` and below the next
+    /// stronger one, like `[3, 1, 1]` for straights beating three of a kind as in poker.
+    #[must_use]
+    pub fn with_straights(mut self, beats: Vec<usize>) -> Self {
+        self.straight_beats = Some(beats);
+        self
+    }
+
+    #[must_use]
+    pub const fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    /// The strength of `card`, starting at 0 for the weakest, or `None` if it is not played.
+    #[must_use]
+    pub fn strength(&self, card: Card) -> Option<u8> {
+        self.order.iter().position(|&c| c == card).and_then(|strength| u8::try_from(strength).ok())
+    }
+
+    #[must_use]
+    pub fn is_wildcard(&self, card: Card) -> bool {
+        self.wildcards.contains(&card)
+    }
+
+    /// Checks `cards` make a hand of these rules.
+    ///
+    /// # Errors
+    /// What is wrong with the hand, if it has the wrong number of cards or a card that is not
+    /// played.
+    pub fn check(&self, cards: &[Card]) -> Result<(), String> {
+        if cards.len() != self.hand_size {
+            return Err(format!("Expected {} cards", self.hand_size));
+        }
+        cards.iter().find(|&&card| self.strength(card).is_none()).map_or(Ok(()), |card| Err(format!("{card} is not played")))
+    }
+
+    /// The best type of `cards`, with the wildcards standing in for the cards making it.
+    #[must_use]
+    pub fn hand_type(&self, cards: &[Card]) -> HandType {
+        let (wildcards, natural): (Vec<Card>, Vec<Card>) = cards.iter().partition(|&&card| self.is_wildcard(card));
+        let mut groups: Vec<usize> = vec![];
+        let mut sorted = natural.clone();
+        sorted.sort_unstable();
+        for run in sorted.chunk_by(|a, b| a == b) {
+            groups.push(run.len());
+        }
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        // the wildcards are always best off joining the largest group
+        match groups.first_mut() {
+            Some(largest) => *largest += wildcards.len(),
+            None => groups.push(wildcards.len()),
+        }
+        let groups = HandType::Groups(groups);
+        if self.is_straight(&natural) && self.compare_types(&HandType::Straight, &groups).is_gt() {
+            HandType::Straight
+        } else {
+            groups
+        }
+    }
+
+    /// `cards` with the wildcards replaced by the cards making the best hand type.
+    ///
+    /// Wildcards join the largest group, the strongest of equally large ones, or fill the gaps of
+    /// the strongest straight.
+    #[must_use]
+    pub fn substitute(&self, cards: &[Card]) -> Vec<Card> {
+        let natural: Vec<Card> = cards.iter().copied().filter(|&card| !self.is_wildcard(card)).collect();
+        if natural.len() == cards.len() {
+            return natural;
+        }
+        let stand_ins = if self.hand_type(cards) == HandType::Straight {
+            self.straight_gaps(&natural)
+        } else {
+            let largest = natural
+                .iter()
+                .max_by_key(|&&card| (natural.iter().filter(|&&other| other == card).count(), self.strength(card)))
+                .or_else(|| self.order.iter().rev().find(|&&card| !self.is_wildcard(card)));
+            largest.map(|&card| vec![card; cards.len() - natural.len()]).unwrap_or_default()
+        };
+        let mut stand_ins = stand_ins.into_iter();
+        cards.iter().map(|&card| if self.is_wildcard(card) { stand_ins.next().unwrap_or(card) } else { card }).collect()
+    }
+
+    /// The cards missing from the `natural` cards of a hand for the strongest straight.
+    fn straight_gaps(&self, natural: &[Card]) -> Vec<Card> {
+        let strengths: Vec<usize> = natural.iter().filter_map(|&card| self.strength(card)).map(usize::from).collect();
+        let highest_start = self.order.len() - self.hand_size;
+        let start = strengths.iter().min().map_or(highest_start, |&low| low.min(highest_start));
+        (start..start + self.hand_size).filter(|strength| !strengths.contains(strength)).map(|strength| self.order[strength]).collect()
+    }
+
+    /// Whether the `natural` cards of a hand, the wildcards left out, can be completed to a
+    /// straight by the wildcards.
+    fn is_straight(&self, natural: &[Card]) -> bool {
+        if self.straight_beats.is_none() || self.order.len() < self.hand_size {
+            return false;
+        }
+        let mut strengths: Vec<u8> = natural.iter().filter_map(|&card| self.strength(card)).collect();
+        strengths.sort_unstable();
+        let distinct = strengths.windows(2).all(|pair| pair[0] != pair[1]);
+        let span = strengths.first().zip(strengths.last()).map_or(0, |(low, high)| usize::from(high - low));
+        distinct && span < self.hand_size
+    }
+
+    /// Orders hand types by strength.
+    #[must_use]
+    pub fn compare_types(&self, a: &HandType, b: &HandType) -> Ordering {
+        self.type_key(a).cmp(&self.type_key(b))
+    }
+
+    /// A key ordering `hand_type` among the others: a straight sorts right after the groups it
+    /// beats.
+    fn type_key(&self, hand_type: &HandType) -> (Vec<usize>, bool) {
+        match hand_type {
+            HandType::Groups(groups) => (groups.clone(), false),
+            HandType::Straight => (self.straight_beats.clone().unwrap_or_default(), true),
+        }
+    }
+
+    /// What a hand of `cards` of type `hand_type` is sorted by.
+    #[must_use]
+    pub fn sort_order(&self, cards: &[Card], hand_type: &HandType) -> SortOrder {
+        SortOrder {
+            hand_type: self.type_key(hand_type),
+            cards: cards.iter().map(|&card| self.strength(card).unwrap_or_default()).collect(),
+        }
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.chars().map(|c| Card::try_from(c).unwrap()).collect()
+    }
+
+    fn groups(groups: &[usize]) -> HandType {
+        HandType::Groups(groups.to_vec())
+    }
+
+    #[test]
+    fn it_gets_hand_types() {
+        let standard = Ruleset::standard();
+        assert_eq!(standard.hand_type(&cards("32T3K")), groups(&[2, 1, 1, 1]));
+        assert_eq!(standard.hand_type(&cards("KTJJT")), groups(&[2, 2, 1]));
+        assert_eq!(standard.hand_type(&cards("23456")), groups(&[1, 1, 1, 1, 1]));
+        let jokers = Ruleset::jokers();
+        assert_eq!(jokers.hand_type(&cards("KTJJT")), groups(&[4, 1]));
+        assert_eq!(jokers.hand_type(&cards("JJJJJ")), groups(&[5]));
+        assert!(jokers.compare_types(&groups(&[3, 2]), &groups(&[3, 1, 1])).is_gt());
+    }
+
+    #[test]
+    fn it_supports_several_wildcards() {
+        let rules = Ruleset::standard().with_wildcards(&[Two, Jack]);
+        assert_eq!(rules.hand_type(&cards("2J345")), groups(&[3, 1, 1]));
+        assert_eq!(rules.hand_type(&cards("2JJ22")), groups(&[5]));
+    }
+
+    #[test]
+    fn it_supports_any_hand_size() {
+        let rules = Ruleset::standard().with_hand_size(3);
+        assert_eq!(rules.hand_type(&cards("KKA")), groups(&[2, 1]));
+        assert_eq!(rules.check(&cards("KKAA")), Err("Expected 3 cards".to_owned()));
+        let rules = Ruleset::jokers().with_hand_size(7);
+        assert_eq!(rules.hand_type(&cards("KKJAA2J")), groups(&[4, 2, 1]));
+    }
+
+    #[test]
+    fn it_supports_custom_orders() {
+        let rules = Ruleset::new(&[Ace, King, Queen]);
+        assert_eq!((rules.strength(Ace), rules.strength(Queen), rules.strength(Two)), (Some(0), Some(2), None));
+        assert_eq!(rules.check(&cards("AKQA2")), Err("2 is not played".to_owned()));
+    }
+
+    #[test]
+    fn it_substitutes_wildcards() {
+        let jokers = Ruleset::jokers();
+        assert_eq!(jokers.substitute(&cards("KTJJT")), cards("KTTTT"));
+        assert_eq!(jokers.substitute(&cards("KTJKT")), cards("KTKKT"));
+        assert_eq!(jokers.substitute(&cards("JJJJJ")), cards("AAAAA"));
+        assert_eq!(jokers.substitute(&cards("32T3K")), cards("32T3K"));
+        let straights = Ruleset::jokers().with_straights(vec![3, 1, 1]);
+        assert_eq!(straights.substitute(&cards("2JJ56")), cards("23456"));
+        assert_eq!(straights.substitute(&cards("JKJTA")), cards("9KQTA"));
+    }
+
+    #[test]
+    fn it_names_hand_types() {
+        let names: Vec<String> = [&[5][..], &[4, 1], &[3, 2], &[3, 1, 1], &[2, 2, 1], &[2, 1, 1, 1], &[1, 1, 1, 1, 1], &[3, 3, 1]]
+            .into_iter()
+            .map(|sizes| groups(sizes).to_string())
+            .collect();
+        assert_eq!(names, ["five of a kind", "four of a kind", "full house", "three of a kind", "two pair", "one pair", "high card", "groups of 3, 3"]);
+        assert_eq!(HandType::Straight.to_string(), "straight");
+    }
+
+    #[test]
+    fn it_supports_straights() {
+        let rules = Ruleset::standard().with_straights(vec![3, 1, 1]);
+        assert_eq!(rules.hand_type(&cards("65432")), HandType::Straight);
+        assert_eq!(rules.hand_type(&cards("TJQKA")), HandType::Straight);
+        assert_eq!(rules.hand_type(&cards("A2345")), groups(&[1, 1, 1, 1, 1]));
+        assert!(rules.compare_types(&HandType::Straight, &groups(&[3, 1, 1])).is_gt());
+        assert!(rules.compare_types(&HandType::Straight, &groups(&[3, 2])).is_lt());
+        // jokers complete a straight, unless the other cards already pair up
+        let rules = Ruleset::jokers().with_straights(vec![3, 1, 1]);
+        assert_eq!(rules.hand_type(&cards("23J56")), HandType::Straight);
+        assert_eq!(rules.hand_type(&cards("2JJ56")), HandType::Straight);
+        assert_eq!(rules.hand_type(&cards("22J56")), groups(&[3, 1, 1]));
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day08::{Day08, INPUT};
+
+fn day08(c: &mut Criterion) {
+    bench::<Day08>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench::<Day08>(c, "example2", include_str!("../src/example2.txt"), &[2]);
+    bench_input::<Day08>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day08);
+criterion_main!(benches);
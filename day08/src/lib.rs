@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use aoc_common::{parse, ParseError, Solution};
+use color_eyre::eyre::eyre;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{alphanumeric1, line_ending, space1};
+use nom::combinator::value;
+use nom::error::Error;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, separated_pair, terminated, tuple};
+use color_eyre::Result;
+
+aoc_common::embedded_input!();
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Network;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(network: &Self::Input) -> Result<Self::Answer1> {
+        network
+            .follow_instructions("AAA", "ZZZ")
+            .ok_or_else(|| eyre!("Cannot follow steps"))
+    }
+
+    fn part2(network: &Self::Input) -> Result<Self::Answer2> {
+        network
+            .follow_ghost_instructions("A", "Z")
+            .ok_or_else(|| eyre!("Cannot follow steps"))
+    }
+}
+
+/// A node of a [`Network`], interned as an index into its nodes.
+pub type NodeId = u32;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+/// A network of nodes with a left and a right neighbour each, and the instructions to walk it.
+///
+/// The nodes are interned in the order they are defined: the neighbours of node `i` are
+/// `left[i]` and `right[i]`, so walking the network only looks up indices.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Network {
+    /// The name of every node, by index.
+    names: Vec<String>,
+    /// The index of every node, by name.
+    ids: HashMap<String, NodeId>,
+    left: Vec<NodeId>,
+    right: Vec<NodeId>,
+    instructions: Vec<Instruction>,
+}
+
+impl Network {
+    /// The node named `name`, if there is one.
+    #[must_use]
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name of `node`.
+    ///
+    /// # Panics
+    /// If `node` is not a node of this network.
+    #[must_use]
+    pub fn name(&self, node: NodeId) -> &str {
+        &self.names[node as usize]
+    }
+
+    fn step(&self, node: NodeId, instruction: Instruction) -> NodeId {
+        match instruction {
+            Instruction::Left => self.left[node as usize],
+            Instruction::Right => self.right[node as usize],
+        }
+    }
+
+    /// Every node visited by following the instructions from `start` forever, `start` included.
+    ///
+    /// # Panics
+    /// If `start` is not a node of this network.
+    pub fn walk(&self, start: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        assert!((start as usize) < self.names.len(), "{start} is not a node");
+        let mut node = start;
+        std::iter::once(start).chain(self.instructions.iter().cycle().map(move |&instruction| {
+            node = self.step(node, instruction);
+            node
+        }))
+    }
+
+    fn follow_instructions(&self, start: &str, end: &str) -> Option<usize> {
+        let end = self.id(end)?;
+        self.walk(self.id(start)?).position(|node| node == end)
+    }
+
+    /// Whether every node ends with `suffix`.
+    fn ends_with(&self, suffix: &str) -> Vec<bool> {
+        self.names.iter().map(|name| name.ends_with(suffix)).collect()
+    }
+
+    fn get_cycle_length(&self, start: NodeId, is_end: &[bool]) -> Option<usize> {
+        self.walk(start).position(|node| is_end[node as usize])
+    }
+
+    fn follow_ghost_instructions(&self, start_suffix: &str, end_suffix: &str) -> Option<usize> {
+        let is_end = self.ends_with(end_suffix);
+        let starts = self.ends_with(start_suffix);
+        (0..)
+            .zip(starts)
+            .filter(|&(_, is_start)| is_start)
+            .map(|(start, _)| self.get_cycle_length(start, &is_end))
+            .collect::<Option<Vec<usize>>>()?
+            .into_iter()
+            .reduce(lcm)
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let instruction = alt((
+            value(Instruction::Left, tag::<_, _, Error<_>>("L")),
+            value(Instruction::Right, tag_no_case("R"))));
+
+        let node = tuple((
+            terminated(alphanumeric1, tuple((space1, tag("="), space1))),
+            delimited(tag("("), separated_pair(
+                alphanumeric1, tuple((tag(","), space1)), alphanumeric1,
+            ), tag(")"))
+        ));
+
+        let parser = separated_pair(
+            many1(
+                instruction
+            ),
+            tuple((line_ending, line_ending)),
+            separated_list1(
+                line_ending,
+                node,
+            ),
+        );
+        let (instructions, nodes_vec) = parse::complete(s.trim(), parser)
+            .map_err(|e| e.within(s, s.trim()))?;
+        let mut names = Vec::with_capacity(nodes_vec.len());
+        let mut ids = HashMap::with_capacity(nodes_vec.len());
+        for &(root, _) in &nodes_vec {
+            let id = NodeId::try_from(names.len()).map_err(|_| ParseError::at(s, root, "Too many nodes"))?;
+            if ids.insert(root.to_string(), id).is_some() {
+                return Err(ParseError::at(s, root, format!("Node {root} is defined twice")));
+            }
+            names.push(root.to_string());
+        }
+        let id = |name: &str| ids.get(name).copied().ok_or_else(|| ParseError::at(s, name, format!("Node {name} is not defined")));
+        let (mut left, mut right) = (Vec::with_capacity(names.len()), Vec::with_capacity(names.len()));
+        for (_, (l, r)) in nodes_vec {
+            left.push(id(l)?);
+            right.push(id(r)?);
+        }
+        Ok(Self {
+            names,
+            ids,
+            left,
+            right,
+            instructions,
+        })
+    }
+}
+
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    // wikipedia ftw.
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+const fn lcm(a: usize, b: usize) -> usize {
+    (a * b) / gcd(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn it_parses_input() {
+        let network: Network = EXAMPLE.parse().unwrap();
+        assert_eq!(network.instructions, vec![Instruction::Left, Instruction::Left, Instruction::Right]);
+        assert_eq!(network.names, ["AAA", "BBB", "ZZZ"]);
+        assert_eq!((network.left, network.right), (vec![1, 0, 2], vec![1, 2, 2]));
+        assert_eq!(network.ids["BBB"], 1);
+    }
+
+    #[test]
+    fn it_rejects_undefined_nodes() {
+        let err = "L\n\nAAA = (BBB, AAA)".parse::<Network>().unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (3, 8, "Node BBB is not defined"));
+        let err = "L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)".parse::<Network>().unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (4, "Node AAA is defined twice"));
+    }
+
+    #[test]
+    fn it_walks_by_index() {
+        let network: Network = EXAMPLE.parse().unwrap();
+        let walk: Vec<&str> = network.walk(network.id("AAA").unwrap()).take(7).map(|node| network.name(node)).collect();
+        assert_eq!(walk, ["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ"]);
+        assert_eq!(network.id("XXX"), None);
+        assert_eq!(network.follow_instructions("XXX", "ZZZ"), None);
+    }
+
+    #[test]
+    fn it_walks_millions_of_steps() {
+        // a ring of a thousand nodes, walked right by R and back by L
+        let nodes: Vec<String> = (0..1000).map(|i| format!("{i:03}")).collect();
+        let lines: Vec<String> = (0..1000).map(|i| format!("{} = ({}, {})", nodes[i], nodes[(i + 999) % 1000], nodes[(i + 1) % 1000])).collect();
+        let network: Network = format!("RRL\n\n{}", lines.join("\n")).parse().unwrap();
+        let node = network.walk(network.id("000").unwrap()).nth(3_000_000).unwrap();
+        assert_eq!(network.name(node), "000");
+        assert_eq!(network.follow_instructions("000", "999"), Some(2993));
+    }
+
+    #[test]
+    fn it_follows_instructions() {
+        let network: Network = EXAMPLE.parse().unwrap();
+        assert_eq!(network.follow_instructions("AAA", "ZZZ"), Some(6));
+    }
+
+    #[test]
+    fn it_follows_ghost_instructions() {
+        let network: Network = EXAMPLE2.parse().unwrap();
+        assert_eq!(network.follow_ghost_instructions("A", "Z"), Some(6));
+    }
+}
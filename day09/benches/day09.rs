@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day09::{Day09, INPUT};
+
+fn day09(c: &mut Criterion) {
+    bench::<Day09>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day09>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day09);
+criterion_main!(benches);
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+use aoc_common::{parse, ParseError, Solution};
+use color_eyre::Result;
+use color_eyre::eyre::eyre;
+
+aoc_common::embedded_input!();
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Sequence>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_sequences_from_input(input)
+    }
+
+    fn part1(sequences: &Self::Input) -> Result<Self::Answer1> {
+        sequences
+            .iter()
+            .cloned()
+            .map(|mut sequence| sequence.next())
+            .sum::<Option<_>>().ok_or_else(|| eyre!("Cannot get next value"))
+    }
+
+    fn part2(sequences: &Self::Input) -> Result<Self::Answer2> {
+        sequences
+            .iter()
+            .cloned()
+            .map(|mut sequence| sequence.next_back())
+            .sum::<Option<_>>().ok_or_else(|| eyre!("Cannot get next back value"))
+    }
+}
+fn get_sequences_from_input(input: &str) -> Result<Vec<Sequence>> {
+    Ok(parse::parse_lines(input.trim())?)
+}
+
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    last: Vec<isize>,
+    first: Vec<isize>,
+}
+
+impl Sequence {
+    /// `initial_data` must not be empty.
+    fn new(initial_data: &[isize]) -> Self {
+        let mut last: Vec<isize> = vec![*initial_data.last().unwrap()];
+        let mut first: Vec<isize> = vec![*initial_data.first().unwrap()];
+        let mut numbers = initial_data.to_vec();
+        // a single number left is extrapolated as constant
+        while numbers.len() > 1 && numbers.iter().any(|n| *n != 0) {
+            let mut new_numbers: Vec<isize> = vec![];
+            for i in 0..(numbers.len() - 1) {
+                new_numbers.push(numbers[i + 1] - numbers[i]);
+            }
+            first.push(*new_numbers.first().unwrap());
+            last.push(*new_numbers.last().unwrap());
+            numbers = new_numbers;
+        }
+        last.reverse();
+        first.reverse();
+        Self { last , first}
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let iv: Vec<isize> = s
+            .split_ascii_whitespace()
+            .map(|n| n.parse().map_err(|e| ParseError::at(s, n, e)))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if iv.is_empty() {
+            return Err(ParseError::at(s, s, "Expected numbers"));
+        }
+        Ok(Self::new(&iv))
+    }
+}
+
+impl Iterator for Sequence {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for i in 1..self.last.len() {
+            self.last[i] += self.last[i - 1];
+        }
+        self.last.last().copied()
+    }
+}
+
+impl DoubleEndedIterator for Sequence {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        for i in 1..self.first.len() {
+            self.first[i] -= self.first[i - 1];
+        }
+        self.first.last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use color_eyre::eyre::eyre;
+    use super::*;
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn it_calculates_linear_sequence() {
+        let mut seq = Sequence::new(&[0, 3, 6, 9]);
+        assert_eq!(seq.take(3).collect::<Vec<_>>(), vec![12, 15, 18]);
+    }
+
+    #[test]
+    fn it_calculates_higher_sequences() {
+        let mut seq = Sequence::new(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(seq.next(), Some(28));
+
+        let mut seq = Sequence::new(&[10, 13, 16, 21, 30, 45]);
+        assert_eq!(seq.next(), Some(68));
+    }
+
+    #[test]
+    fn it_sums_iterators() -> Result<()> {
+        let mut iterators: Vec<Sequence> = get_sequences_from_input(EXAMPLE)?;
+
+        let s: isize = iterators
+            .iter_mut()
+            .map(std::iter::Iterator::next)
+            .sum::<Option<_>>().ok_or_else(|| eyre!("Cannot get next value"))?;
+        assert_eq!(s, 114);
+        Ok(())
+    }
+
+    #[test]
+    fn it_gets_other_end_of_sequence() -> Result<()> {
+        let mut iterators: Vec<Sequence> = get_sequences_from_input(EXAMPLE)?;
+        let next_backs: Vec<isize> = iterators
+            .iter_mut()
+            .map(std::iter::DoubleEndedIterator::next_back)
+            .collect::<Option<Vec<_>>>().ok_or_else(|| eyre!("Cannot get last next_back"))?;
+        assert_eq!(next_backs, vec![-3, 0, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_malformed_sequences() {
+        let err = get_sequences_from_input("1 2\n3 x 5").unwrap_err().downcast::<ParseError>().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+        let mut sequence: Sequence = "1 2".parse().unwrap();
+        assert_eq!(sequence.next(), Some(3));
+    }
+
+    #[test]
+    fn it_deals_with_negative_starts() {
+        let mut sequence = Sequence::new(&[-6, -7, -8, -9, -10]);
+        assert_eq!(sequence.next_back(), Some(-5));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day10::{Day10, INPUT};
+
+fn day10(c: &mut Criterion) {
+    bench::<Day10>(c, "example2", include_str!("../src/example2.txt"), &[1, 2]);
+    bench::<Day10>(c, "example8", include_str!("../src/example8.txt"), &[1, 2]);
+    bench_input::<Day10>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day10);
+criterion_main!(benches);
@@ -0,0 +1,241 @@
+#![feature(let_chains)]
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use aoc_common::direction::Direction;
+use aoc_common::grid::{self, Coordinate};
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+
+
+aoc_common::embedded_input!();
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        grid.get_furthest_distance_on_loop().ok_or_else(|| eyre!("Cannot get loop"))
+    }
+
+    fn part2(grid: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        grid.inside_tiles().ok_or_else(|| eyre!("Cannot count tiles inside loop"))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+enum Tile {
+    Ground,
+    Start,
+    Pipe(Direction, Direction),
+}
+
+impl Tile {
+    fn connects(self, direction: Direction) -> bool {
+        match self {
+            Self::Ground => false,
+            Self::Start => true,
+            Self::Pipe(a, b) => a == direction || b == direction
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::Pipe(Direction::North, Direction::South)),
+            '-' => Ok(Self::Pipe(Direction::West, Direction::East)),
+            'L' => Ok(Self::Pipe(Direction::North, Direction::East)),
+            'J' => Ok(Self::Pipe(Direction::North, Direction::West)),
+            '7' => Ok(Self::Pipe(Direction::South, Direction::West)),
+            'F' => Ok(Self::Pipe(Direction::South, Direction::East)),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err(ParseError::new("Not a tile", value))
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ground => write!(f, "."),
+            Self::Start => write!(f, "S"),
+            Self::Pipe(a, b) => {
+                match (a, b) {
+                    (Direction::North, Direction::South) => write!(f, "|"),
+                    (Direction::West, Direction::East) => write!(f, "-"),
+                    (Direction::North, Direction::East) => write!(f, "L"),
+                    (Direction::North, Direction::West) => write!(f, "J"),
+                    (Direction::South, Direction::West) => write!(f, "7"),
+                    (Direction::South, Direction::East) => write!(f, "F"),
+                    _ => write!(f, "?")
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    tiles: grid::Grid<Tile>,
+    start: Coordinate,
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles: grid::Grid<Tile> = s.trim().parse().map_err(|e: ParseError| e.within(s, s.trim()))?;
+        let start = tiles.position(|t| *t == Tile::Start).unwrap_or_default();
+        Ok(Self { tiles, start })
+    }
+}
+
+impl Grid {
+    fn neighbours(&self, coordinate: Coordinate) -> Option<[Coordinate; 2]> {
+        let current = self.tiles.get(coordinate)?;
+        let neighbours: Vec<Coordinate> = Direction::ALL
+            .into_iter()
+            .filter(|&direction| current.connects(direction))
+            .filter_map(|direction| {
+                self.tiles.step(coordinate, direction).filter(|n| self.tiles[*n].connects(direction.opposite()))
+            })
+            .collect();
+        neighbours.try_into().ok()
+    }
+
+    fn get_loop(&self) -> Option<Vec<Coordinate>> {
+        let mut current = self.start;
+        let mut loop_tiles = vec![];
+        let mut previous = self.start;
+        let mut first_loop = true;
+        while first_loop || current != self.start {
+            let neighbours = self.neighbours(current)?;
+            loop_tiles.push(current);
+            if neighbours[0] == previous || first_loop {
+                previous = current;
+                current = neighbours[1];
+            } else {
+                previous = current;
+                current = neighbours[0];
+            };
+            if first_loop { first_loop = false };
+        }
+        Some(loop_tiles)
+    }
+
+    fn get_furthest_distance_on_loop(&self) -> Option<usize> {
+        self.get_loop().map(|l| l.len() / 2)
+    }
+
+    /// Counts the tiles enclosed by the loop with a scanline: going along a row, every loop tile
+    /// connecting north crosses the loop, so a tile is inside after an odd number of crossings.
+    fn inside_tiles(&self) -> Option<usize> {
+        let loop_tiles: HashSet<Coordinate> = HashSet::from_iter(self.get_loop()?);
+        // the start connects to whichever of its neighbours continue the loop
+        let start_neighbours = self.neighbours(self.start)?;
+        let start_north = self.tiles.step(self.start, Direction::North).is_some_and(|north| start_neighbours.contains(&north));
+        let crosses = |tile: &Tile| if *tile == Tile::Start { start_north } else { tile.connects(Direction::North) };
+        let mut inside = false;
+        let mut count = 0;
+        for (coordinate, tile) in self.tiles.indexed_iter() {
+            if coordinate.0 == 0 {
+                inside = false;
+            }
+            if !loop_tiles.contains(&coordinate) {
+                count += usize::from(inside);
+            } else if crosses(tile) {
+                inside = !inside;
+            }
+        }
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_and_displays() {
+        let input = include_str!("example1.txt").replace("\r\n", "\n");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.to_string().trim(), input);
+        let input = include_str!("example2.txt").replace("\r\n", "\n");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.to_string().trim(), input);
+        let input = include_str!("example3.txt").replace("\r\n", "\n");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.to_string().trim(), input);
+    }
+
+    #[test]
+    fn it_gets_simple_loop() {
+        let input = include_str!("example2.txt");
+        let grid: Grid = input.parse().unwrap();
+        let mut l = grid.get_loop().unwrap();
+        l.sort_unstable();
+        let mut expected = [(1, 1), (1, 2), (1, 3), (2, 3), (3, 3), (3, 2), (3, 1), (2, 1)];
+        expected.sort_unstable();
+        assert_eq!(l, expected);
+    }
+
+    #[test]
+    fn it_gets_furthest_point() {
+        let input = include_str!("example2.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.get_furthest_distance_on_loop(), Some(4));
+        let input = include_str!("example3.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.get_furthest_distance_on_loop(), Some(4));
+        let input = include_str!("example4.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.get_furthest_distance_on_loop(), Some(8));
+    }
+
+    #[test]
+    fn it_counts_inside_tiles1() {
+        let input = include_str!("example5.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.inside_tiles(), Some(4));
+    }
+
+    #[test]
+    fn it_counts_inside_tiles2() {
+        let input = include_str!("example6.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.inside_tiles(), Some(4));
+    }
+
+    #[test]
+    fn it_counts_inside_tiles3() {
+        let input = include_str!("example7.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.inside_tiles(), Some(8));
+    }
+
+    #[test]
+    fn it_counts_inside_tiles4() {
+        let input = include_str!("example8.txt");
+        let grid: Grid = input.parse().unwrap();
+        assert_eq!(grid.inside_tiles(), Some(10));
+    }
+}
\ No newline at end of file
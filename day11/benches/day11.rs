@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day11::{Day11, INPUT};
+
+fn day11(c: &mut Criterion) {
+    bench::<Day11>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day11>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day11);
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day12::{Day12, INPUT};
+
+fn day12(c: &mut Criterion) {
+    bench::<Day12>(c, "example", include_str!("../src/example.txt"), &[1]);
+    // part 2 takes minutes, even on the example
+    bench_input::<Day12>(c, INPUT, &[1]);
+}
+
+criterion_group!(benches, day12);
+criterion_main!(benches);
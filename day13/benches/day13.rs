@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day13::{Day13, INPUT};
+
+fn day13(c: &mut Criterion) {
+    bench::<Day13>(c, "example1", include_str!("../src/example1.txt"), &[1, 2]);
+    bench_input::<Day13>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day13);
+criterion_main!(benches);
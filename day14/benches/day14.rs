@@ -0,0 +1,16 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day14::{Day14, INPUT};
+
+fn day14(c: &mut Criterion) {
+    bench::<Day14>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day14>(c, INPUT, &[1, 2]);
+}
+
+criterion_group! {
+    name = benches;
+    // part 2 spins the real platform, hashing every state, until the cycles repeat
+    config = Criterion::default().sample_size(10);
+    targets = day14
+}
+criterion_main!(benches);
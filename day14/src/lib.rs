@@ -0,0 +1,212 @@
+#![feature(let_chains)]
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::{ParseError, Solution};
+
+aoc_common::embedded_input!();
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(platform: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        let mut platform = platform.clone();
+        platform.tilt_north();
+        Ok(platform.get_load())
+    }
+
+    fn part2(platform: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        let mut platform = platform.clone();
+        platform.run_cycles(1_000_000_000);
+        Ok(platform.get_load())
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Block {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Block {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Self::Round),
+            '#' => Ok(Self::Cube),
+            '.' => Ok(Self::Empty),
+            _ => Err(ParseError::new("Not a block", value)),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Platform {
+    grid: Grid<Block>,
+}
+
+impl Platform {
+    fn tilt_north(&mut self) {
+        for y in 1..self.grid.height() {
+            for x in 0..self.grid.width() {
+                if self.grid[(x, y)] == Block::Round {
+                    self.move_rock((x, y), 0, -1);
+                }
+            }
+        }
+    }
+
+    fn tilt_south(&mut self) {
+        for y in (0..(self.grid.height() - 1)).rev() {
+            for x in 0..self.grid.width() {
+                if self.grid[(x, y)] == Block::Round {
+                    self.move_rock((x, y), 0, 1);
+                }
+            }
+        }
+    }
+
+    fn tilt_east(&mut self) {
+        for x in (0..(self.grid.width() - 1)).rev() {
+            for y in 0..self.grid.height() {
+                if self.grid[(x, y)] == Block::Round {
+                    self.move_rock((x, y), 1, 0);
+                }
+            }
+        }
+    }
+
+    fn tilt_west(&mut self) {
+        for x in 1..self.grid.width() {
+            for y in 0..self.grid.height() {
+                if self.grid[(x, y)] == Block::Round {
+                    self.move_rock((x, y), -1, 0);
+                }
+            }
+        }
+    }
+
+    fn move_rock(&mut self, rock: Coordinate, x_offset: isize, y_offset: isize) {
+        let mut target = rock;
+        while let Some(next) = self.grid.offset(target, x_offset, y_offset) && self.grid[next] == Block::Empty {
+            target = next;
+        }
+
+        self.grid[rock] = Block::Empty;
+        self.grid[target] = Block::Round;
+    }
+
+    fn get_load(&self) -> usize {
+        self.grid
+            .indexed_iter()
+            .filter(|(_, b)| **b == Block::Round)
+            .map(|((_, y), _)| self.grid.height() - y)
+            .sum()
+    }
+
+    fn run_cycles(&mut self, cycles: usize) {
+        let mut visited: HashMap<Grid<Block>, usize> = HashMap::new();
+        let mut i = 1;
+        let mut cycle_found = false;
+        while i <= cycles {
+            self.next();
+            if let Some(last_seen) = visited.insert(self.grid.clone(), i) && !cycle_found {
+                let cycle_length = i - last_seen;
+                while i + cycle_length < cycles {
+                    i += cycle_length;
+                }
+                cycle_found = true;
+            }
+            i += 1;
+        }
+    }
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Round => write!(f, "O"),
+            Self::Cube => write!(f, "#"),
+            Self::Empty => write!(f, "."),
+        }
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Iterator for Platform {
+    type Item = ();
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+        Some(())
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { grid: s.parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Platform;
+
+    const EXAMPLE1: &str = include_str!("example.txt");
+
+    #[test]
+    fn it_parses_and_displays() {
+        let platform: Platform = EXAMPLE1.parse().unwrap();
+        assert_eq!(platform.to_string(), EXAMPLE1);
+    }
+
+    #[test]
+    fn it_tilts_north() {
+        let mut platform: Platform = EXAMPLE1.parse().unwrap();
+        platform.tilt_north();
+        assert_eq!(platform.to_string(), include_str!("example1_expected.txt"));
+    }
+
+    #[test]
+    fn it_gets_load() {
+        let mut platform: Platform = EXAMPLE1.parse().unwrap();
+        platform.tilt_north();
+        assert_eq!(platform.get_load(), 136);
+    }
+
+    #[test]
+    fn it_cycles() {
+        let mut platform: Platform = EXAMPLE1.parse().unwrap();
+        platform.next();
+        assert_eq!(platform.to_string(), include_str!("example1_cycled1.txt"));
+    }
+
+    #[test]
+    fn it_cycles_a_lot() {
+        let mut platform: Platform = EXAMPLE1.parse().unwrap();
+        platform.run_cycles(1_000_000_000);
+        assert_eq!(platform.get_load(), 64);
+    }
+}
\ No newline at end of file
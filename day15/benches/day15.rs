@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day15::{Day15, INPUT};
+
+fn day15(c: &mut Criterion) {
+    bench::<Day15>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day15>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day15);
+criterion_main!(benches);
@@ -0,0 +1,186 @@
+#![feature(ascii_char)]
+#![feature(inline_const)]
+
+use std::str::FromStr;
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+use color_eyre::Report;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+aoc_common::embedded_input!();
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        // the steps are hashed by their ASCII codes
+        if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::at(input, &input[i..i + c.len_utf8()], "Not an ASCII character").into());
+        }
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        Ok(input.split(',').map(|s| s.reindeer_hash() as usize).sum())
+    }
+
+    fn part2(input: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        let operations: Vec<Operation> = input
+            .split(',')
+            .map(|step| step.parse().map_err(|e: ParseError| e.within(input, step)))
+            .collect::<Result<Vec<Operation>, _>>()?;
+        let boxes: Boxes = operations.try_into()?;
+        Ok(boxes.focusing_power())
+    }
+}
+
+trait ReindeerHash {
+    fn reindeer_hash(&self) -> u8;
+}
+
+impl ReindeerHash for &str {
+    fn reindeer_hash(&self) -> u8 {
+        let mut hash: u8 = 0;
+        for c in self.trim().as_ascii().unwrap() {
+            hash = hash.wrapping_add(c.to_u8());
+            hash = hash.wrapping_mul(17);
+        }
+        hash
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct Lens {
+    label: String,
+    focal_length: u8,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+enum Operation {
+    Remove(String),
+    AddReplace(Lens),
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w+)(?:-|=(\d))$").unwrap());
+        let step = s.trim();
+        let cap = RE.captures(step).ok_or_else(|| ParseError::at(s, step, "Expected `label-` or `label=focal length`"))?;
+        if let Some(v) = cap.get(2) {
+            let focal_length: u8 = v.as_str().parse().map_err(|e| ParseError::at(s, &step[v.range()], e))?;
+            Ok(Self::AddReplace(Lens { focal_length, label: cap[1].to_string() }))
+        } else {
+            Ok(Self::Remove(cap[1].to_string()))
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Boxes {
+    boxes: [Vec<Lens>; 256],
+}
+
+impl Boxes {
+    const fn new() -> Self {
+        Self {
+            boxes: [const { vec![] }; 256],
+        }
+    }
+
+    fn focusing_power(&self) -> usize {
+        let mut s = 0;
+        for (idx, b) in self.boxes.iter().enumerate() {
+            for (slot, lens) in b.iter().enumerate() {
+                s += (idx + 1) * (slot + 1) * (lens.focal_length as usize);
+            }
+        }
+        s
+    }
+}
+
+
+impl TryFrom<Vec<Operation>> for Boxes {
+    type Error = Report;
+
+    fn try_from(operations: Vec<Operation>) -> Result<Self, Self::Error> {
+        let mut boxes = Self::new();
+        for op in operations {
+            match op {
+                Operation::Remove(l) => {
+                    let b = boxes.boxes.get_mut(l.as_str().reindeer_hash() as usize).ok_or_else(|| eyre!("cannot get hash"))?;
+                    let idx = b.iter().position(|p| p.label == *l);
+                    if let Some(idx) = idx {
+                        b.remove(idx);
+                    }
+                }
+                Operation::AddReplace(l) => {
+                    let hash = l.label.as_str().reindeer_hash();
+                    let b = boxes.boxes.get_mut(hash as usize).ok_or_else(|| eyre!("cannot get hash"))?;
+                    let idx = b.iter().position(|p| p.label == l.label);
+                    if let Some(idx) = idx {
+                        b[idx] = l;
+                    } else {
+                        b.push(l);
+                    }
+                }
+            }
+        }
+        Ok(boxes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hashes() {
+        assert_eq!("rn=1".reindeer_hash(), 30);
+        assert_eq!("cm-".reindeer_hash(), 253);
+    }
+
+    #[test]
+    fn it_hashes_sums() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let hash: usize = input.split(',').map(|s| s.reindeer_hash() as usize).sum();
+        assert_eq!(hash, 1320);
+    }
+
+    #[test]
+    fn it_parses_operation() {
+        let op: Operation = "rn=1".parse().unwrap();
+        assert_eq!(op, Operation::AddReplace(Lens { label: "rn".to_string(), focal_length: 1 }));
+
+        let op: Operation = "cm-".parse().unwrap();
+        assert_eq!(op, Operation::Remove("cm".to_string()));
+
+        assert!("cm=x".parse::<Operation>().is_err());
+        assert!("cm-1".parse::<Operation>().is_err());
+        assert!(Day15::parse("rn=1,cm–").is_err());
+    }
+
+    #[test]
+    fn it_gets_boxes() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let operations: Vec<Operation> = input.split(',').map(str::parse).collect::<Result<Vec<Operation>, _>>().unwrap();
+        let boxes: Boxes = operations.try_into().unwrap();
+        assert_eq!(boxes.boxes[0].len(), 2);
+        assert_eq!(boxes.boxes[3].len(), 3);
+    }
+
+    #[test]
+    fn it_gets_focusing_power() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let operations: Vec<Operation> = input.split(',').map(str::parse).collect::<Result<Vec<Operation>, _>>().unwrap();
+        let boxes: Boxes = operations.try_into().unwrap();
+        assert_eq!(boxes.focusing_power(), 145);
+    }
+}
\ No newline at end of file
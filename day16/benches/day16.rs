@@ -0,0 +1,16 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day16::{Day16, INPUT};
+
+fn day16(c: &mut Criterion) {
+    bench::<Day16>(c, "example1", include_str!("../src/example1.txt"), &[1, 2]);
+    bench_input::<Day16>(c, INPUT, &[1, 2]);
+}
+
+criterion_group! {
+    name = benches;
+    // part 2 energises the real contraption once from every tile along its edges
+    config = Criterion::default().sample_size(10);
+    targets = day16
+}
+criterion_main!(benches);
@@ -0,0 +1,164 @@
+use std::collections::{BTreeSet, HashSet};
+use std::str::FromStr;
+use aoc_common::direction::Direction;
+use aoc_common::grid::Grid;
+use aoc_common::{ParseError, Solution};
+use color_eyre::Result;
+use itertools::Itertools;
+
+aoc_common::embedded_input!();
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Contraption;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(contraption: &Self::Input) -> Result<Self::Answer1> {
+        Ok(contraption.energise(Ray { x: 0, y: 0, direction: Direction::East }).iter().map(|r| (r.x, r.y)).unique().count())
+    }
+
+    fn part2(contraption: &Self::Input) -> Result<Self::Answer2> {
+        Ok(contraption.best_energisation())
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+enum Block {
+    Empty,
+    MirrorSlash,
+    MirrorBackslash,
+    SplitterVertical,
+    SplitterHorizontal,
+}
+
+impl TryFrom<char> for Block {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '|' => Ok(Self::SplitterVertical),
+            '-' => Ok(Self::SplitterHorizontal),
+            '/' => Ok(Self::MirrorSlash),
+            '\\' => Ok(Self::MirrorBackslash),
+            _ => Err(ParseError::new("Not a block", value))
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Ray {
+    x: usize,
+    y: usize,
+    direction: Direction,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Contraption {
+    grid: Grid<Block>,
+}
+
+impl Contraption {
+    fn best_energisation(&self) -> usize {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let mut best = 0;
+        for x in 0..width {
+            best = best.max(self.energise(Ray { x, y: 0, direction: Direction::South }).iter().map(|r| (r.x, r.y)).unique().count());
+            best = best.max(self.energise(Ray { x, y: height - 1, direction: Direction::North }).iter().map(|r| (r.x, r.y)).unique().count());
+        }
+
+        for y in 0..height {
+            best = best.max(self.energise(Ray { x: 0, y, direction: Direction::East }).iter().map(|r| (r.x, r.y)).unique().count());
+            best = best.max(self.energise(Ray { x: width - 1, y, direction: Direction::West }).iter().map(|r| (r.x, r.y)).unique().count());
+        }
+        best
+    }
+    fn energise(&self, ray: Ray) -> HashSet<Ray> {
+        let mut existing_rays: HashSet<Ray> = HashSet::new();
+        let mut rays = BTreeSet::new();
+        rays.insert(ray);
+        loop {
+            let Some(mut ray) = rays.pop_first() else { return existing_rays; };
+            while existing_rays.insert(ray) {
+                match self.grid[(ray.x, ray.y)] {
+                    Block::Empty => {}
+                    Block::MirrorSlash => {
+                        ray.direction = match ray.direction {
+                            Direction::North => Direction::East,
+                            Direction::South => Direction::West,
+                            Direction::East => Direction::North,
+                            Direction::West => Direction::South,
+                        }
+                    }
+                    Block::MirrorBackslash => {
+                        ray.direction = match ray.direction {
+                            Direction::North => Direction::West,
+                            Direction::South => Direction::East,
+                            Direction::East => Direction::South,
+                            Direction::West => Direction::North,
+                        }
+                    }
+                    Block::SplitterVertical => {
+                        if ray.direction.is_horizontal() {
+                            ray.direction = Direction::South;
+                            let new_ray = Ray { x: ray.x, y: ray.y, direction: Direction::North };
+                            rays.insert(new_ray);
+                        }
+                    }
+                    Block::SplitterHorizontal => {
+                        if ray.direction.is_vertical() {
+                            ray.direction = Direction::East;
+                            let new_ray = Ray { x: ray.x, y: ray.y, direction: Direction::West };
+                            rays.insert(new_ray);
+                        }
+                    }
+                }
+                let Some((x, y)) = self.grid.step((ray.x, ray.y), ray.direction) else { break; };
+                ray.x = x;
+                ray.y = y;
+            }
+        }
+    }
+}
+
+impl FromStr for Contraption {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self { grid: s.parse()? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+
+    #[test]
+    fn it_parses_map() {
+        let contraption: Contraption = EXAMPLE1.parse().unwrap();
+        assert_eq!(contraption.grid.height(), 10);
+        assert_eq!(contraption.grid.width(), 10);
+        assert_eq!(contraption.grid[(1, 0)], Block::SplitterVertical);
+    }
+
+    #[test]
+    fn it_gets_energisation() {
+        let contraption: Contraption = EXAMPLE1.parse().unwrap();
+        assert_eq!(contraption.energise(Ray { direction: Direction::East, x: 0, y: 0 }).iter().map(|r| (r.x, r.y)).unique().count(), 46);
+    }
+
+    #[test]
+    fn it_gets_best_energisation() {
+        let contraption: Contraption = EXAMPLE1.parse().unwrap();
+        assert_eq!(contraption.best_energisation(), 51);
+    }
+}
\ No newline at end of file
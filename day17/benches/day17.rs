@@ -0,0 +1,17 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day17::{Day17, INPUT};
+
+fn day17(c: &mut Criterion) {
+    bench::<Day17>(c, "example1", include_str!("../src/example1.txt"), &[1, 2]);
+    bench::<Day17>(c, "example2", include_str!("../src/example2.txt"), &[2]);
+    bench_input::<Day17>(c, INPUT, &[1, 2]);
+}
+
+criterion_group! {
+    name = benches;
+    // both parts run A* over every block and heading of the real city
+    config = Criterion::default().sample_size(10);
+    targets = day17
+}
+criterion_main!(benches);
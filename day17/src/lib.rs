@@ -0,0 +1,137 @@
+#![feature(let_chains)]
+
+use std::str::FromStr;
+use aoc_common::direction::Direction;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::{pathfinding, ParseError, Solution};
+use color_eyre::eyre::eyre;
+
+aoc_common::embedded_input!();
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = City;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(city: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        city.get_min_heat_loss(1, 3).ok_or_else(|| eyre!("Cannot reach the factory"))
+    }
+
+    fn part2(city: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        city.get_min_heat_loss(4, 10).ok_or_else(|| eyre!("Cannot reach the factory"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct City {
+    /// The heat lost when entering a block
+    cost: Grid<usize>,
+}
+
+impl City {
+    fn get_min_heat_loss(&self, min_movement: usize, max_movement: usize) -> Option<usize> {
+        let end = (self.cost.width() - 1, self.cost.height() - 1);
+        let crucible = Crucible { city: self, min_movement, max_movement };
+        // every block on the way costs at least the cheapest one, which may be free
+        let cheapest = self.cost.iter().min().copied().unwrap_or_default();
+        let path = pathfinding::astar(
+            &crucible,
+            (None, (0, 0)),
+            |(_, coordinate)| *coordinate == end,
+            |(_, (x, y))| (x.abs_diff(end.0) + y.abs_diff(end.1)) * cheapest,
+        )?;
+        Some(path.cost)
+    }
+}
+
+/// The city as seen by a crucible, which has to move between `min_movement` and `max_movement`
+/// blocks in a straight line before turning.
+struct Crucible<'a> {
+    city: &'a City,
+    min_movement: usize,
+    max_movement: usize,
+}
+
+impl pathfinding::Graph for Crucible<'_> {
+    /// Direction is the movement towards this location, meaning it can't continue the same direction
+    type Node = (Option<Direction>, Coordinate);
+
+    fn neighbours(&self, &(previous, position): &Self::Node) -> impl Iterator<Item = (Self::Node, usize)> {
+        let mut neighbours = vec![];
+        for direction in Direction::ALL {
+            // it can neither continue the same direction nor reverse it
+            if previous.is_some_and(|previous| previous.is_vertical() == direction.is_vertical()) {
+                continue;
+            }
+            let mut heat_loss = 0;
+            let mut neighbour = position;
+            for i in 1..=self.max_movement {
+                let Some(next) = self.city.cost.step(neighbour, direction) else { break; };
+                neighbour = next;
+                heat_loss += self.city.cost[neighbour];
+                if i >= self.min_movement {
+                    neighbours.push(((Some(direction), neighbour), heat_loss));
+                }
+            }
+        }
+        neighbours.into_iter()
+    }
+}
+
+impl FromStr for City {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cost = Grid::parse_with(s, |c| {
+            c.to_digit(10).and_then(|v| v.try_into().ok()).ok_or("Not a digit")
+        })?;
+        Ok(Self { cost })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn it_parses_city() {
+        let city: City = EXAMPLE1.parse().unwrap();
+        assert_eq!(city.cost.width(), 13);
+        assert_eq!(city.cost.height(), 13);
+        assert_eq!(city.cost[(0, 0)], 2);
+    }
+
+    #[test]
+    fn it_finds_cost() {
+        let city: City = EXAMPLE1.parse().unwrap();
+        assert_eq!(city.get_min_heat_loss(1, 3), Some(102));
+    }
+
+    #[test]
+    fn it_finds_cost_of_ultra_crucible() {
+        let city: City = EXAMPLE1.parse().unwrap();
+        assert_eq!(city.get_min_heat_loss(4, 10), Some(94));
+    }
+
+    #[test]
+    fn it_finds_cost_of_ultra_crucible2() {
+        let city: City = EXAMPLE2.parse().unwrap();
+        assert_eq!(city.get_min_heat_loss(4, 10), Some(71));
+    }
+
+    #[test]
+    fn it_finds_cost_through_free_blocks() {
+        let city: City = "0700\n3030\n0703\n7000\n6700".parse().unwrap();
+        assert_eq!(city.get_min_heat_loss(1, 3), Some(6));
+    }
+}
\ No newline at end of file
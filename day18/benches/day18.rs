@@ -0,0 +1,16 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day18::{Day18, INPUT};
+
+fn day18(c: &mut Criterion) {
+    bench::<Day18>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day18>(c, INPUT, &[1, 2]);
+}
+
+criterion_group! {
+    name = benches;
+    // part 1 tests every cell of the real lagoon's bounding box against the trench polygon
+    config = Criterion::default().sample_size(10);
+    targets = day18
+}
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day19::{Day19, INPUT};
+
+fn day19(c: &mut Criterion) {
+    bench::<Day19>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    bench_input::<Day19>(c, INPUT, &[1, 2]);
+}
+
+criterion_group!(benches, day19);
+criterion_main!(benches);
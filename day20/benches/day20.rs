@@ -0,0 +1,13 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day20::{Day20, INPUT};
+
+fn day20(c: &mut Criterion) {
+    bench::<Day20>(c, "example1", include_str!("../src/example1.txt"), &[1]);
+    bench::<Day20>(c, "example2", include_str!("../src/example2.txt"), &[1]);
+    // part 2 needs an `rx` module, which only the real input has, and takes too long on it
+    bench_input::<Day20>(c, INPUT, &[1]);
+}
+
+criterion_group!(benches, day20);
+criterion_main!(benches);
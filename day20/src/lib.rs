@@ -0,0 +1,316 @@
+extern crate core;
+
+use std::collections::{HashMap, VecDeque};
+use std::ops::AddAssign;
+
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+aoc_common::embedded_input!();
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const PART2_SOLVED: bool = false;
+    type Input = Vec<Module>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(modules: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        let out = pulse(&mut modules.clone(), 1000);
+        Ok(out.high * out.low)
+    }
+
+    fn part2(modules: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        wait_for_rx(&mut modules.clone()).ok_or_else(|| eyre!("There is no `rx` module"))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Output {
+    high: usize,
+    low: usize,
+}
+
+impl AddAssign for Output {
+    fn add_assign(&mut self, rhs: Self) {
+        self.high += rhs.high;
+        self.low += rhs.low;
+    }
+}
+
+impl Output {
+    fn increment(&mut self, high: bool) {
+        if high {
+            self.high += 1;
+        } else {
+            self.low += 1;
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Module {
+    Flipflop(Flipflop),
+    Conjunction(Conjunction),
+    Broadcaster(Broadcaster),
+    Noop(String),
+}
+
+impl Module {
+    /// The destinations of the module, a noop has none.
+    const fn destinations_mut(&mut self) -> Option<&mut Vec<usize>> {
+        match self {
+            Self::Flipflop(f) => Some(&mut f.destinations),
+            Self::Conjunction(c) => Some(&mut c.destinations),
+            Self::Broadcaster(b) => Some(&mut b.destinations),
+            Self::Noop(_) => None,
+        }
+    }
+
+    fn pulse(&mut self, signal: &Signal, queue: &mut VecDeque<Signal>) -> Output {
+        match self {
+            Self::Flipflop(f) => {
+                if signal.high {
+                    return Output { high: 0, low: 0 };
+                }
+                f.state = !f.state;
+                let mut output = Output { high: 0, low: 0 };
+                for destination in &f.destinations {
+                    queue.push_back(Signal::new(f.state, signal.destination, *destination));
+                    output.increment(f.state);
+                }
+                output
+            }
+            Self::Conjunction(c) => {
+                c.last_pulse.insert(signal.sender, signal.high);
+                let o = !c.last_pulse.values().all(|v| *v);
+                let mut output = Output { high: 0, low: 0 };
+                for destination in &c.destinations {
+                    queue.push_back(Signal::new(o, signal.destination, *destination));
+                    output.increment(o);
+                }
+                output
+            }
+            Self::Broadcaster(b) => {
+                let mut output = Output { high: 0, low: 0 };
+                for dest in &b.destinations {
+                    queue.push_back(Signal::new(signal.high, signal.destination, *dest));
+                    output.increment(signal.high);
+                }
+                output
+            }
+            Self::Noop(_) => { Output { high: 0, low: 0 } }
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Flipflop(f) => &f.name,
+            Self::Conjunction(c) => &c.name,
+            Self::Broadcaster(_) => "broadcaster",
+            Self::Noop(s) => s,
+        }
+    }
+}
+
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Signal {
+    high: bool,
+    sender: usize,
+    destination: usize,
+}
+
+impl Signal {
+    const fn new(high: bool, sender: usize, destination: usize) -> Self {
+        Self {
+            high,
+            sender,
+            destination,
+        }
+    }
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct Flipflop {
+    destinations: Vec<usize>,
+    name: String,
+    state: bool,
+
+}
+
+
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct Conjunction {
+    destinations: Vec<usize>,
+    last_pulse: HashMap<usize, bool>,
+    name: String,
+}
+
+
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct Broadcaster {
+    destinations: Vec<usize>,
+}
+
+/// Splits a line into the module and its destinations.
+fn split_line<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(" -> ").ok_or_else(|| ParseError::at(input, line, "Expected `module -> destinations`"))
+}
+
+fn parse(input: &str) -> Result<Vec<Module>, ParseError> {
+    let mut out: Vec<Module> = vec![];
+    let mut name_index: HashMap<String, usize> = HashMap::new();
+    for (idx, line) in input.lines().enumerate() {
+        let (left, _) = split_line(input, line)?;
+        let name = left.trim_start_matches(['&', '%']).to_string();
+        if name_index.insert(name.clone(), idx).is_some() {
+            return Err(ParseError::at(input, left, "Duplicate module"));
+        }
+        if left == "broadcaster" {
+            out.push(Module::Broadcaster(Broadcaster::default()));
+        } else if left.starts_with('%') {
+            let f = Flipflop {
+                name,
+                ..Default::default()
+            };
+            out.push(Module::Flipflop(f));
+        } else if left.starts_with('&') {
+            let c = Conjunction {
+                name,
+                ..Default::default()
+            };
+            out.push(Module::Conjunction(c));
+        } else {
+            return Err(ParseError::at(input, left, "Expected `broadcaster`, a flip-flop or a conjunction"));
+        }
+    }
+    if !out.iter().any(|m| matches!(m, Module::Broadcaster(_))) {
+        return Err(ParseError::new("There is no broadcaster", ""));
+    }
+    // First run finished, now we can resolve the dependencies
+    for line in input.lines() {
+        let (left, right) = split_line(input, line)?;
+        let left = left.trim_start_matches(['%', '&']);
+        for target in right.split(", ") {
+            if !name_index.contains_key(target) {
+                out.push(Module::Noop(target.to_string()));
+                name_index.insert(target.to_string(), out.len() - 1);
+            }
+            // every module with a line is a broadcaster, flip-flop or conjunction
+            if let Some(destinations) = out[name_index[left]].destinations_mut() {
+                destinations.push(name_index[target]);
+            }
+            if let Module::Conjunction(c) = &mut out[name_index[target]] {
+                c.last_pulse.insert(name_index[left], false);
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[allow(unused)]
+fn display_signal(signal: &Signal, modules: &[Module]) -> String {
+    format!("{} -{}-> {}", modules[signal.sender].name(), if signal.high { "high" } else { "low" }, modules[signal.destination].name())
+}
+
+fn pulse(modules: &mut [Module], count: usize) -> Output {
+    let mut queue = VecDeque::new();
+    let mut pulses = Output { low: 0, high: 0 };
+    let broadcaster = modules.iter().position(|p| matches!(p, Module::Broadcaster(_))).unwrap();
+    for _ in 0..count {
+        queue.push_back(Signal::new(false, 0, broadcaster));
+        pulses.low += 1;
+        while let Some(signal) = queue.pop_front() {
+            //println!("{}", display_signal(&signal, modules));
+            pulses += modules[signal.destination].pulse(&signal, &mut queue);
+        }
+    }
+    pulses
+}
+
+
+fn wait_for_rx(modules: &mut [Module]) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let broadcaster = modules.iter().position(|p| matches!(p, Module::Broadcaster(_))).unwrap();
+    let rx = modules.iter().position(|p| p.name() == "rx")?;
+    let mut c = 0;
+    loop {
+        c += 1;
+        queue.push_back(Signal::new(false, 0, broadcaster));
+        while let Some(signal) = queue.pop_front() {
+            if !signal.high && signal.destination == rx {
+                return Some(c);
+            }
+            //println!("{}", display_signal(&signal, modules));
+            modules[signal.destination].pulse(&signal, &mut queue);
+            // if queue.iter().any(|s| s.destination == rx && !s.high) {
+            //     return c;
+            // }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn it_parses_input() {
+        let modules = parse(EXAMPLE1).unwrap();
+        assert_eq!(modules.len(), 5);
+        assert!(matches!(modules[0], Module::Broadcaster(_)));
+        assert_eq!(modules.iter().position(|p| matches!(p, Module::Broadcaster(_))).unwrap(), 0);
+        let Module::Broadcaster(b) = &modules[0] else { panic!() };
+        assert_eq!(b.destinations.len(), 3);
+        assert_eq!(b.destinations, vec![1, 2, 3]);
+        let Module::Conjunction(c) = &modules[4] else { panic!() };
+        assert_eq!(c.last_pulse.len(), 1);
+    }
+
+    #[test]
+    fn it_rejects_malformed_modules() {
+        let err = parse("broadcaster -> a\n%a -> b\n$b -> a").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "$b"));
+        assert_eq!(parse("%a -> b").unwrap_err().message, "There is no broadcaster");
+        assert_eq!(parse("broadcaster -> a\n%a b").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn it_pulses() {
+        let mut modules = parse(EXAMPLE1).unwrap();
+        let out = pulse(&mut modules, 1);
+        assert_eq!(out, Output {
+            high: 4,
+            low: 8,
+        });
+        let out = pulse(&mut modules, 1000);
+        assert_eq!(out.high * out.low, 32_000_000);
+    }
+
+    #[test]
+    fn it_parses_example2() {
+        let modules = parse(EXAMPLE2).unwrap();
+        assert_eq!(modules.len(), 6);
+    }
+
+    #[test]
+    fn it_pulses_example2() {
+        let mut modules = parse(EXAMPLE2).unwrap();
+        assert_eq!(pulse(&mut modules, 1), Output {low: 4, high: 4});
+        assert_eq!(pulse(&mut modules, 1), Output {low: 4, high: 2});
+        assert_eq!(pulse(&mut modules, 1), Output {low: 5, high: 3});
+        assert_eq!(pulse(&mut modules, 1), Output {low: 4, high: 2});
+        let mut modules = parse(EXAMPLE2).unwrap();
+        let out = pulse(&mut modules, 1000);
+        assert_eq!(out.high * out.low, 11_687_500);
+    }
+}
\ No newline at end of file
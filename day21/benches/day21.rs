@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day21::{Day21, INPUT};
+
+fn day21(c: &mut Criterion) {
+    bench::<Day21>(c, "example", include_str!("../src/example.txt"), &[1]);
+    // part 2 is not solved yet
+    bench_input::<Day21>(c, INPUT, &[1]);
+}
+
+criterion_group!(benches, day21);
+criterion_main!(benches);
@@ -0,0 +1,144 @@
+use std::str::FromStr;
+use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::pathfinding::{self, Graph};
+use aoc_common::{ParseError, Solution};
+use color_eyre::eyre::eyre;
+aoc_common::embedded_input!();
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const PART2_SOLVED: bool = false;
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        let out = map.distances(64);
+        Ok(out.iter().filter(|v| **v <= 64 && **v % 2 == 0).count())
+    }
+
+    fn part2(_map: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        Err(eyre!("Day 21 part 2 is not solved yet"))
+    }
+}
+
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Block {
+    Garden,
+    Rock,
+}
+
+pub struct Map {
+    grid: Grid<Block>,
+    start: Coordinate,
+}
+
+impl Graph for Map {
+    type Node = Coordinate;
+
+    fn neighbours(&self, coordinate: &Coordinate) -> impl Iterator<Item = (Coordinate, usize)> {
+        self.grid.neighbours4(*coordinate).filter(|n| self.grid[*n] == Block::Garden).map(|n| (n, 1))
+    }
+}
+
+impl Map {
+    /// The steps needed to reach each garden plot, or `usize::MAX` if it is more than `max_distance` steps away.
+    fn distances(&self, max_distance: usize) -> Grid<usize> {
+        let mut distances = Grid::filled(self.grid.width(), self.grid.height(), usize::MAX);
+        for (coordinate, distance) in pathfinding::bfs_distances(self, self.start, max_distance) {
+            distances[coordinate] = distance;
+        }
+        distances
+    }
+    #[allow(unused)]
+    fn result_to_string(&self, result: &Grid<usize>) -> String {
+        Grid::from_fn(self.grid.width(), self.grid.height(), |coordinate| (result[coordinate], self.grid[coordinate]))
+            .display_with(|&(r, block)| {
+                if r < usize::MAX && r % 2 == 0 {
+                    'O'
+                } else {
+                    match block {
+                        Block::Garden => '.',
+                        Block::Rock => '#',
+                    }
+                }
+            })
+            .to_string()
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '#' => Ok(Block::Rock),
+            '.' | 'S' => Ok(Block::Garden),
+            _ => Err("Not a garden plot or rock"),
+        })?;
+        let start = s
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| line.chars().position(|c| c == 'S').map(|x| (x, y)))
+            .ok_or_else(|| ParseError::new("No start position", ""))?;
+        Ok(Self { grid, start })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+    const EXAMPLE2X2: &str = include_str!("example2.txt");
+    const EXAMPLE3X3: &str = include_str!("example3.txt");
+    #[test]
+    fn it_parses_example() {
+        let map: Map = EXAMPLE.parse().unwrap();
+        assert_eq!(map.grid.height(), 11);
+        assert_eq!(map.grid.width(), 11);
+        assert_eq!(map.start, (5, 5));
+    }
+
+    #[test]
+    fn it_gets_distances() {
+        let map: Map = EXAMPLE.parse().unwrap();
+        let result = map.distances(6);
+        assert_eq!(result.iter().filter(|v| **v <= 6 && **v % 2 == 0).count(), 16);
+    }
+
+    #[test]
+    fn it_displays_output() {
+        let map: Map = EXAMPLE.parse().unwrap();
+        let result = map.distances(6);
+        let expected = r"...........
+.....###.#.
+.###.##.O#.
+.O#O#O.O#..
+O.O.#.#.O..
+.##O.O####.
+.##.O#O..#.
+.O.O.O.##..
+.##.#.####.
+.##O.##.##.
+...........
+";
+        assert_eq!(map.result_to_string(&result), expected);
+    }
+
+    #[test]
+    fn it_finds_even_number_of_steps() {
+        let map: Map = EXAMPLE3X3.parse().unwrap();
+        let result = map.distances(usize::MAX);
+        assert_eq!(50, result.iter().filter(|v| **v <= 10 && **v % 2 == 0).count());
+        println!("{}", map.result_to_string(&result.map(|v| if *v <= 10 { *v } else { usize::MAX })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day22::{Day22, INPUT};
+
+fn day22(c: &mut Criterion) {
+    bench::<Day22>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+}
+
+fn day22_input(c: &mut Criterion) {
+    // part 1 lets the real bricks fall again with each one removed in turn, which takes minutes,
+    // and part 2 takes even longer
+    let parts: &[u8] = if cfg!(feature = "slow-benches") { &[1] } else { &[] };
+    bench_input::<Day22>(c, INPUT, parts);
+}
+
+criterion_group!(benches, day22);
+criterion_group! {
+    name = input_benches;
+    config = Criterion::default().sample_size(10);
+    targets = day22_input
+}
+criterion_main!(benches, input_benches);
@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day23::{Day23, INPUT};
+
+fn day23(c: &mut Criterion) {
+    bench::<Day23>(c, "example", include_str!("../src/example.txt"), &[1, 2]);
+    // part 2 takes seconds on the real input
+    bench_input::<Day23>(c, INPUT, &[1]);
+}
+
+criterion_group!(benches, day23);
+criterion_main!(benches);
@@ -0,0 +1,368 @@
+#![feature(let_chains)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use aoc_common::direction::Direction;
+use aoc_common::grid;
+use aoc_common::pathfinding::Graph;
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+
+aoc_common::embedded_input!();
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    /// The hiking map, edges are only found by the parts.
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(grid: &Self::Input) -> color_eyre::Result<Self::Answer1> {
+        let mut grid = grid.clone();
+        grid.find_edges(true);
+        Ok(grid.longest_path_dijkstra())
+    }
+
+    fn part2(grid: &Self::Input) -> color_eyre::Result<Self::Answer2> {
+        let mut grid = grid.clone();
+        grid.find_edges(false);
+        Ok(grid.longest_path())
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum Block {
+    Path,
+    Forest,
+    /// A slope can only be walked down, in its direction.
+    Slope(Direction),
+}
+
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path => write!(f, "."),
+            Self::Forest => write!(f, "#"),
+            Self::Slope(direction) => write!(f, "{direction}"),
+        }
+    }
+}
+
+impl TryFrom<char> for Block {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Path),
+            '#' => Ok(Self::Forest),
+            '^' | '>' | 'v' | '<' => Ok(Self::Slope(Direction::try_from(value)?)),
+            _ => Err(ParseError::new("Not a path, forest or slope", value))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+struct Edge {
+    start: usize,
+    end: usize,
+    length: usize,
+    // maybe remove later?
+    path: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Grid {
+    tiles: grid::Grid<Block>,
+    edges: HashSet<Edge>,
+    start: usize,
+    end: usize,
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles: grid::Grid<Block> = s.parse()?;
+        if tiles.width() < 3 || tiles.height() < 2 {
+            return Err(ParseError::new("The map is too small for a start and an end", ""));
+        }
+        let end = tiles.len() - 2;
+        Ok(Self {
+            tiles,
+            edges: HashSet::new(),
+            start: 1,
+            end,
+        })
+    }
+}
+
+/// The intersections connected by the edges found by `find_edges()`.
+impl Graph for Grid {
+    type Node = usize;
+
+    fn neighbours(&self, node: &usize) -> impl Iterator<Item = (usize, usize)> {
+        self.edges.iter().filter(move |edge| edge.start == *node).map(|edge| (edge.end, edge.length))
+    }
+}
+
+impl Grid {
+    /// since we come from one direction, we can go in at most 3 directions. But iterating through all 4 is easier
+    fn tile_neighbours(&self, position: usize, previous: usize, impassible_slopes: bool) -> [Option<usize>; 4] {
+        let mut res: [Option<usize>; 4] = [None; 4];
+        let coordinate = self.tiles.coordinate_of(position);
+        for (i, direction) in Direction::ALL.into_iter().enumerate() {
+            let Some(neighbour) = self.tiles.step(coordinate, direction) else { continue; };
+            let tile = self.tiles[neighbour];
+            let neighbour = self.tiles.index_of(neighbour).expect("step stays inside the grid");
+            if neighbour != previous && tile != Block::Forest
+                && (!impassible_slopes || tile != Block::Slope(direction.opposite())) {
+                res[i] = Some(neighbour);
+            }
+        }
+        res
+    }
+
+    fn find_edges(&mut self, slopes_are_impassible: bool) {
+        let current_edge = Edge { start: self.start, ..Default::default() };
+        let previous = self.start;
+        let position = self.start + self.tiles.width();
+        self.follow_edge(current_edge, position, previous, slopes_are_impassible);
+    }
+
+    fn follow_edge(&mut self, mut current_edge: Edge, mut position: usize, mut previous: usize, slopes_are_impassible: bool) {
+        while position != self.end {
+            current_edge.length += 1;
+
+            let neighbours = self.tile_neighbours(position, previous, slopes_are_impassible);
+            if neighbours.iter().filter_map(|c| *c).count() == 1 {
+                // no intersection
+                #[cfg(debug_assertions)]
+                {
+                    current_edge.path.push(position);
+                }
+                previous = position;
+                position = neighbours.into_iter().find_map(|n| n).unwrap();
+                continue;
+            }
+            // we have reached an intersection!
+            current_edge.end = position;
+            if self.edges.insert(current_edge) {
+                for neighbour in neighbours.into_iter().flatten() {
+                    self.follow_edge(Edge { start: position, ..Default::default() }, neighbour, position, slopes_are_impassible);
+                }
+            }
+            return;
+        }
+        current_edge.length += 1;
+        current_edge.end = position;
+        self.edges.insert(current_edge);
+    }
+
+    fn longest_path(&self) -> usize {
+        let mut res = 0;
+        self.longest_path_bruteforce(self.start, &mut HashSet::new(), 0, &mut res);
+        res
+    }
+
+    /// Dijkstra with negative edge weight, assuming we are in a DAG.
+    /// if not: brute force should work, not too many edges
+    ///
+    /// The shortest path algorithms of `aoc_common::pathfinding` can't be used for this, as they stop
+    /// at the first path found to a node.
+    fn longest_path_dijkstra(&self) -> usize {
+        assert!(!self.edges.is_empty(), "First run `find_edges()`");
+        let mut visited_vertices = HashSet::new();
+        let mut distances: HashMap<usize, usize> = HashMap::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(self.start);
+        distances.insert(self.start, 0);
+        while let Some(u) = queue.pop_front() {
+            let distance_to_u = distances[&u];
+            let new_node = visited_vertices.insert(u);
+            for (end, length) in self.neighbours(&u) {
+                distances.entry(end).and_modify(|distance| {
+                    if *distance < distance_to_u + length {
+                        *distance = distance_to_u + length;
+                        if new_node {
+                            queue.push_back(end);
+                        }
+                    }
+                }).or_insert_with(|| {
+                    if new_node {
+                        queue.push_back(end);
+                    }
+                    distance_to_u + length
+                });
+            }
+        }
+        distances[&self.end]
+    }
+
+    /// Yay recursive brute force algorithm
+    fn longest_path_bruteforce(&self, position: usize, visited: &mut HashSet<usize>, distance: usize, longest_distance: &mut usize) {
+        if position == self.end {
+            if distance > *longest_distance {
+                *longest_distance = distance;
+            }
+            return;
+        }
+        let unvisited_neighbours = self.neighbours(&position).filter(|(end, _)| !visited.contains(end)).collect_vec();
+        for (end, length) in unvisited_neighbours {
+            visited.insert(end);
+            self.longest_path_bruteforce(end, visited, distance + length, longest_distance);
+            visited.remove(&end);
+        }
+    }
+
+    /// Returns a Mermaid.js compatible string for a flowchart
+    #[allow(unused)]
+    fn mermaid(&self) -> String {
+        assert!(!self.edges.is_empty(), "First run `find_edges()`");
+        let unique_edges = self.edges.iter().unique_by(|e| {
+            (e.length, e.start.min(e.end), e.start.max(e.end))
+        }).collect_vec();
+        dbg!(unique_edges.len());
+        dbg!(self.edges.len());
+        let arrow_sign = if unique_edges.len() == self.edges.len() {
+            '>'
+        } else {
+            '-'
+        };
+        let mut out = String::from("flowchart TD\n");
+        for &Edge { start, length, end, .. } in unique_edges {
+            out.push_str(&format!("\t{start} --{arrow_sign}|{length}| {end}\n"));
+        }
+        out
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, c) in self.tiles.iter().enumerate() {
+            if i > 0 && i % self.tiles.width() == 0 {
+                writeln!(f)?;
+            }
+            if *c == Block::Path && let Some(edge) = self.edges.iter().position(|e| e.path.contains(&i)) {
+                write!(f, "{}", u8::try_from(65 + (edge % 26)).unwrap() as char)?;
+            } else {
+                write!(f, "{c}")?;
+            }
+        }
+        if !self.edges.is_empty() {
+            writeln!(f)?;
+            for (i, edge) in self.edges.iter().enumerate() {
+                writeln!(f, "{}: {{ start: {}, end: {}, length: {} }}", u8::try_from(65 + (i % 26)).unwrap() as char, edge.start, edge.end, edge.length)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/*
+Hypothesis: Are all intersections surrounded by direction markers?
+There are different intersections:
+- 1->2 (e.g. B)
+- 2->1 (e.g. G) -> Not really an intersection, can be ignored.
+- 2->2 (e.g. H)
+A -15-> B
+
+Are we in a DAG? https://en.wikipedia.org/wiki/Longest_path_problem#Acyclic_graphs
+
+*/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn it_parses_example() {
+        let grid: Grid = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.tiles.width(), 23);
+        assert_eq!(grid.tiles[(0, 0)], Block::Forest);
+        assert_eq!(grid.tiles[(1, 0)], Block::Path);
+    }
+
+    #[test]
+    fn it_gets_neighbours() {
+        let grid: Grid = EXAMPLE.parse().unwrap();
+        let width = grid.tiles.width();
+        let neighbours = grid.tile_neighbours(width + 1, grid.start, true);
+        assert_eq!(neighbours.iter().filter_map(|c| *c).collect::<Vec<_>>(), vec![width + 2]);
+    }
+
+    /**
+    ```mermaid
+    flowchart TD
+        80 -->|24| 312
+        312 -->|33| 527
+        312 -->|25| 527
+        118 -->|22| 80
+        1 -->|15| 118
+        304 -->|53| 527
+        304 -->|12| 312
+        118 -->|22| 304
+        80 -->|45| 527
+    ```
+     */
+    #[test]
+    fn it_finds_edges() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(true);
+        println!("{grid}");
+        assert_eq!(grid.edges.len(), 9);
+    }
+
+    #[test]
+    fn it_gets_mermaid_diagram() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(true);
+        let expected = r"flowchart TD
+	80 -->|24| 312
+	312 -->|33| 527
+	312 -->|25| 527
+	118 -->|22| 80
+	1 -->|15| 118
+	304 -->|53| 527
+	304 -->|12| 312
+	118 -->|22| 304
+	80 -->|45| 527";
+        let actual = grid.mermaid();
+        assert!(actual.lines().all(|line| expected.contains(line)));
+    }
+
+    #[test]
+    fn it_finds_longest_path() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(true);
+        assert_eq!(grid.longest_path(), 94);
+    }
+
+    #[test]
+    fn it_finds_longest_path_with_dijkstra() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(true);
+        assert_eq!(grid.longest_path_dijkstra(), 94);
+    }
+
+    #[test]
+    fn it_finds_edges_ignoring_slopes() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(false);
+        assert_eq!(grid.edges.len(), 23);
+    }
+
+    #[test]
+    fn it_finds_longest_path_ignoring_slopes() {
+        let mut grid: Grid = EXAMPLE.parse().unwrap();
+        grid.find_edges(false);
+        assert_eq!(grid.longest_path(), 154);
+    }
+}
\ No newline at end of file
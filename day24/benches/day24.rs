@@ -0,0 +1,12 @@
+use aoc_common::bench::{bench, bench_input};
+use criterion::{criterion_group, criterion_main, Criterion};
+use day24::{Day24, INPUT};
+
+fn day24(c: &mut Criterion) {
+    bench::<Day24>(c, "example", include_str!("../src/example.txt"), &[1]);
+    // part 2 is not solved yet
+    bench_input::<Day24>(c, INPUT, &[1]);
+}
+
+criterion_group!(benches, day24);
+criterion_main!(benches);